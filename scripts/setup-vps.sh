@@ -57,6 +57,8 @@ sudo tee -a /etc/ssh/sshd_config > /dev/null << 'EOF'
 # SSH-TUI Portfolio Configuration
 Match User portfolio
     ForceCommand /opt/ssh-tui/ssh-tui
+    # Lets the app see which key authenticated, to unlock the Admin screen
+    ExposeAuthInfo yes
     PermitTTY yes
     X11Forwarding no
     AllowAgentForwarding no
@@ -0,0 +1,211 @@
+//! GitHub activity for the Activity screen, read from a local JSON cache.
+//!
+//! Sessions never touch the network: `ssh-tui refresh-activity` (run from cron or a systemd
+//! timer) queries the GraphQL endpoint in `SSH_TUI_GITHUB_API` and rewrites the cache, and each
+//! session reads whatever the last refresh left behind.
+
+use std::{fs, path::Path, time::SystemTime};
+
+use color_eyre::{Result, eyre::eyre};
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+
+use crate::{analytics::unix_millis, config::Config, state_files::atomic_write};
+
+/// Repositories kept in the cache, most starred first.
+const TOP_REPOS: usize = 6;
+
+const QUERY: &str = "query($login: String!) {
+  user(login: $login) {
+    contributionsCollection {
+      contributionCalendar {
+        weeks { contributionDays { date contributionCount } }
+      }
+    }
+    repositories(first: 100, ownerAffiliations: OWNER, isFork: false,
+                 orderBy: {field: STARGAZERS, direction: DESC}) {
+      nodes {
+        name
+        description
+        stargazerCount
+        primaryLanguage { name }
+        languages(first: 10, orderBy: {field: SIZE, direction: DESC}) {
+          edges { size node { name } }
+        }
+      }
+    }
+  }
+}";
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Activity {
+    pub user: String,
+    /// Unix milliseconds of the refresh that wrote the cache.
+    pub fetched_at: u64,
+    /// One entry per day, oldest first.
+    pub days: Vec<Day>,
+    pub repos: Vec<Repo>,
+    /// Bytes of code per language across all repositories, largest first.
+    pub languages: Vec<LanguageShare>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Day {
+    /// `YYYY-MM-DD`.
+    pub date: String,
+    pub count: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Repo {
+    pub name: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    pub stars: u32,
+    #[serde(default)]
+    pub language: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LanguageShare {
+    pub name: String,
+    pub bytes: u64,
+}
+
+impl Activity {
+    pub fn total(&self) -> u32 {
+        self.days.iter().map(|day| day.count).sum()
+    }
+
+    /// Most consecutive days with at least one contribution.
+    pub fn longest_streak(&self) -> usize {
+        self.days
+            .split(|day| day.count == 0)
+            .map(<[Day]>::len)
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Heatmap intensity from `0` (nothing) to `4` (the busiest days).
+    pub fn level(&self, count: u32) -> u8 {
+        let busiest = self.days.iter().map(|day| day.count).max().unwrap_or(0);
+        if count == 0 || busiest == 0 {
+            return 0;
+        }
+        (count * 4).div_ceil(busiest).clamp(1, 4) as u8
+    }
+}
+
+/// The cached activity, if a refresh has ever written one.
+pub fn load(path: &Path) -> Option<Activity> {
+    let text = fs::read_to_string(path).ok()?;
+    serde_json::from_str(&text).ok()
+}
+
+/// Fetch the configured user's activity and replace the cache.
+pub fn refresh(config: &Config) -> Result<Activity> {
+    let token = config
+        .github_token
+        .as_deref()
+        .ok_or_else(|| eyre!("SSH_TUI_GITHUB_TOKEN must be set to query the GitHub API"))?;
+    let response: Value = ureq::post(&config.github_api)
+        .set("Authorization", &format!("bearer {token}"))
+        .set("User-Agent", "ssh-tui")
+        .send_json(json!({
+            "query": QUERY,
+            "variables": { "login": config.github_user },
+        }))?
+        .into_json()?;
+    if let Some(errors) = response.get("errors") {
+        return Err(eyre!("GitHub API returned errors: {errors}"));
+    }
+    let user = response
+        .pointer("/data/user")
+        .filter(|user| !user.is_null())
+        .ok_or_else(|| eyre!("no GitHub user named {}", config.github_user))?;
+
+    let activity = Activity {
+        user: config.github_user.clone(),
+        fetched_at: unix_millis(SystemTime::now()),
+        days: parse_days(user),
+        repos: parse_repos(user),
+        languages: parse_languages(user),
+    };
+    save(&config.activity_path(), &activity)?;
+    Ok(activity)
+}
+
+fn parse_days(user: &Value) -> Vec<Day> {
+    let weeks = user
+        .pointer("/contributionsCollection/contributionCalendar/weeks")
+        .and_then(Value::as_array);
+    let mut days: Vec<Day> = weeks
+        .into_iter()
+        .flatten()
+        .filter_map(|week| week.get("contributionDays")?.as_array())
+        .flatten()
+        .filter_map(|day| {
+            Some(Day {
+                date: day.get("date")?.as_str()?.to_string(),
+                count: day.get("contributionCount")?.as_u64()? as u32,
+            })
+        })
+        .collect();
+    days.sort_by(|a, b| a.date.cmp(&b.date));
+    days
+}
+
+fn repo_nodes(user: &Value) -> impl Iterator<Item = &Value> {
+    user.pointer("/repositories/nodes")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+}
+
+fn parse_repos(user: &Value) -> Vec<Repo> {
+    repo_nodes(user)
+        .filter_map(|repo| {
+            Some(Repo {
+                name: repo.get("name")?.as_str()?.to_string(),
+                description: repo
+                    .get("description")
+                    .and_then(Value::as_str)
+                    .map(str::to_string),
+                stars: repo.get("stargazerCount")?.as_u64()? as u32,
+                language: repo
+                    .pointer("/primaryLanguage/name")
+                    .and_then(Value::as_str)
+                    .map(str::to_string),
+            })
+        })
+        .take(TOP_REPOS)
+        .collect()
+}
+
+fn parse_languages(user: &Value) -> Vec<LanguageShare> {
+    let mut languages: Vec<LanguageShare> = Vec::new();
+    let edges = repo_nodes(user)
+        .filter_map(|repo| repo.pointer("/languages/edges")?.as_array())
+        .flatten();
+    for edge in edges {
+        let (Some(name), Some(bytes)) = (
+            edge.pointer("/node/name").and_then(Value::as_str),
+            edge.get("size").and_then(Value::as_u64),
+        ) else {
+            continue;
+        };
+        match languages.iter_mut().find(|language| language.name == name) {
+            Some(language) => language.bytes += bytes,
+            None => languages.push(LanguageShare {
+                name: name.to_string(),
+                bytes,
+            }),
+        }
+    }
+    languages.sort_by_key(|language| std::cmp::Reverse(language.bytes));
+    languages
+}
+
+fn save(path: &Path, activity: &Activity) -> std::io::Result<()> {
+    atomic_write(path, serde_json::to_string_pretty(activity)?.as_bytes())
+}
@@ -0,0 +1,223 @@
+//! Operator access control and the usage statistics shown on the Admin screen.
+//!
+//! Access is granted either by the local `SSH_TUI_ADMIN` flag or by connecting with a public key
+//! listed in the admin allow-list. sshd only tells us which key was used when `ExposeAuthInfo`
+//! is enabled: it then writes the accepted methods to the file named by `SSH_USER_AUTH`.
+
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    fs::{self, File},
+    io::{Read, Seek, SeekFrom},
+    path::Path,
+    time::SystemTime,
+};
+
+use serde::Deserialize;
+
+use crate::{
+    analytics::{Event, Record, unix_millis},
+    calendar::{Date, unix_day},
+    config::Config,
+    host, presence,
+};
+
+/// Sessions without a disconnect older than this are assumed to have died without one.
+const STALE_SESSION_MS: u64 = 12 * 60 * 60 * 1000;
+/// Days shown in the sessions-per-day chart.
+const DAYS_SHOWN: usize = 7;
+
+/// Whether this connection may open the Admin screen.
+pub fn is_authorized(config: &Config) -> bool {
+    if config.admin {
+        return true;
+    }
+    let Some(auth_path) = std::env::var_os("SSH_USER_AUTH") else {
+        return false;
+    };
+    let (Ok(auth), Ok(allowed)) = (
+        fs::read_to_string(auth_path),
+        fs::read_to_string(&config.admin_keys),
+    ) else {
+        return false;
+    };
+    let allowed: HashSet<(&str, &str)> = allowed
+        .lines()
+        .filter(|line| !line.trim_start().starts_with('#'))
+        .filter_map(key_fields)
+        .collect();
+    auth.lines()
+        .filter_map(|line| line.strip_prefix("publickey "))
+        .filter_map(key_fields)
+        .any(|key| allowed.contains(&key))
+}
+
+/// The `(type, base64)` pair of an OpenSSH public key line, ignoring options and comments.
+fn key_fields(line: &str) -> Option<(&str, &str)> {
+    let mut fields = line.split_whitespace();
+    // authorized_keys lines may start with options; the key type is the first `ssh-`/`ecdsa-`
+    // or `sk-` token.
+    let kind = fields.find(|field| {
+        field.starts_with("ssh-") || field.starts_with("ecdsa-") || field.starts_with("sk-")
+    })?;
+    Some((kind, fields.next()?))
+}
+
+/// Host resource usage read from `/proc` (Linux only; fields stay `None` elsewhere).
+#[derive(Debug, Clone, Default)]
+pub struct Resources {
+    pub load: Option<[f64; 3]>,
+    pub mem_used_kb: Option<u64>,
+    pub mem_total_kb: Option<u64>,
+    pub process_rss_kb: Option<u64>,
+    pub uptime_secs: Option<u64>,
+}
+
+impl Resources {
+    pub fn read() -> Self {
+        let memory = host::memory();
+        Self {
+            load: host::load_average(),
+            mem_used_kb: memory.map(|memory| memory.used_kb()),
+            mem_total_kb: memory.map(|memory| memory.total_kb),
+            process_rss_kb: host::process_rss_kb(),
+            uptime_secs: host::uptime_secs(),
+        }
+    }
+}
+
+/// Everything the Admin screen displays, rebuilt periodically from the state directory.
+#[derive(Debug, Clone, Default)]
+pub struct Dashboard {
+    pub active_sessions: usize,
+    pub total_sessions: usize,
+    /// Oldest first, ending today.
+    pub sessions_per_day: Vec<(Date, u64)>,
+    /// Screen name, visits and average dwell in milliseconds; most visited first.
+    pub screens: Vec<(String, u64, u64)>,
+    pub resources: Resources,
+    log: LogTally,
+}
+
+impl Dashboard {
+    /// Bring the dashboard up to date, reading only what was appended to the analytics log since
+    /// the last refresh so the UI does not stall as the log grows.
+    pub fn refresh(&mut self, config: &Config) {
+        if let Some(path) = config.analytics_path() {
+            self.log.catch_up(&path);
+        }
+        let now = unix_millis(SystemTime::now());
+        self.active_sessions = self.log.active_sessions(now);
+        // The presence registry knows exactly who is connected; the log can only guess.
+        if config.presence_dir().is_dir() {
+            self.active_sessions = presence::snapshot(&config.presence_dir()).online;
+        }
+        self.total_sessions = self.log.total_sessions;
+        self.sessions_per_day = self.log.sessions_per_day(now);
+        self.screens = self.log.screens();
+        self.resources = Resources::read();
+    }
+}
+
+/// Running totals over the analytics log and how far into it they have been counted.
+#[derive(Debug, Clone, Default)]
+struct LogTally {
+    /// Bytes of the log already counted; always the end of a complete line.
+    offset: u64,
+    /// Connect time of sessions that have not logged a disconnect.
+    open: HashMap<String, u64>,
+    total_sessions: usize,
+    /// Connects per day since the Unix epoch.
+    per_day: BTreeMap<i64, u64>,
+    /// Visits, total dwell and dwell count per screen.
+    screens: HashMap<String, (u64, u64, u64)>,
+}
+
+impl LogTally {
+    /// Count the complete lines appended to the log at `path` since the last call.
+    fn catch_up(&mut self, path: &Path) {
+        let Ok(mut file) = File::open(path) else {
+            return;
+        };
+        // A log shorter than what was already counted has been replaced; start over.
+        if file.metadata().is_ok_and(|meta| meta.len() < self.offset) {
+            *self = Self::default();
+        }
+        let mut appended = Vec::new();
+        if file.seek(SeekFrom::Start(self.offset)).is_err()
+            || file.read_to_end(&mut appended).is_err()
+        {
+            return;
+        }
+        // A line still being written is left for the next refresh.
+        let complete = appended
+            .iter()
+            .rposition(|&byte| byte == b'\n')
+            .map_or(0, |end| end + 1);
+        let text = String::from_utf8_lossy(&appended[..complete]);
+        for record in parse_lines::<Record>(&text) {
+            self.add(record);
+        }
+        self.offset += complete as u64;
+    }
+
+    fn add(&mut self, record: Record) {
+        match record.event {
+            Event::Connect { .. } => {
+                self.total_sessions += 1;
+                *self.per_day.entry(unix_day(record.ts)).or_default() += 1;
+                self.open.insert(record.session, record.ts);
+            }
+            Event::Disconnect { .. } => {
+                self.open.remove(&record.session);
+            }
+            Event::ScreenEntered { screen } => {
+                self.screens.entry(screen).or_default().0 += 1;
+            }
+            Event::ScreenLeft { screen, dwell_ms } => {
+                let entry = self.screens.entry(screen).or_default();
+                entry.1 += dwell_ms;
+                entry.2 += 1;
+            }
+            Event::Key { .. } | Event::Resize { .. } | Event::Rejected { .. } => {}
+        }
+    }
+
+    fn active_sessions(&self, now: u64) -> usize {
+        self.open
+            .values()
+            .filter(|&&connected| now.saturating_sub(connected) < STALE_SESSION_MS)
+            .count()
+    }
+
+    fn sessions_per_day(&self, now: u64) -> Vec<(Date, u64)> {
+        let today = unix_day(now);
+        (0..DAYS_SHOWN as i64)
+            .rev()
+            .map(|offset| today - offset)
+            .map(|day| {
+                let count = self.per_day.get(&day).copied().unwrap_or(0);
+                (Date::from_unix_days(day), count)
+            })
+            .collect()
+    }
+
+    fn screens(&self) -> Vec<(String, u64, u64)> {
+        let mut screens: Vec<(String, u64, u64)> = self
+            .screens
+            .iter()
+            .map(|(name, &(visits, dwell_total, dwell_count))| {
+                let avg_dwell = dwell_total.checked_div(dwell_count).unwrap_or(0);
+                (name.clone(), visits, avg_dwell)
+            })
+            .collect();
+        screens.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        screens
+    }
+}
+
+/// Parse JSON Lines, skipping lines that are blank, truncated or from an older schema.
+fn parse_lines<T: for<'de> Deserialize<'de>>(text: &str) -> Vec<T> {
+    text.lines()
+        .filter_map(|line| serde_json::from_str(line).ok())
+        .collect()
+}
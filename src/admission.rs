@@ -0,0 +1,194 @@
+//! Decides whether a new connection gets a session or the "server busy" screen.
+//!
+//! Three limits apply, each disabled by setting it to zero: a global cap on concurrent sessions,
+//! a cap on concurrent sessions from one source address, and a cap on connections per source
+//! address per minute. Concurrency comes from the presence registry; connection rates are kept
+//! as one small file of attempt timestamps per source under `ratelimit/` in the state directory.
+//! Connections are checked one at a time under an exclusive lock on `admission.lock`, held until
+//! the admitted session has joined the registry, so a burst cannot slip past the caps.
+//! Sources are identified by a hash of the client address mixed with a random per-install salt,
+//! so no IPs are written to disk and the keys cannot be reversed without the salt.
+
+#[cfg(unix)]
+use std::os::unix::{fs::OpenOptionsExt, net::UnixDatagram};
+use std::{
+    fs::{self, File, OpenOptions},
+    hash::{DefaultHasher, Hash, Hasher},
+    io::{self, Write},
+    path::Path,
+    time::{Duration, SystemTime},
+};
+
+use crate::{
+    analytics::{self, unix_millis},
+    config::Config,
+    presence,
+    state_files::stale_entries,
+};
+
+/// Window over which [`Config::rate_limit`] connections are allowed.
+const RATE_WINDOW: Duration = Duration::from_secs(60);
+const SALT_LEN: usize = 32;
+
+/// Why a connection was turned away.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Rejection {
+    /// Too many connections from this source within the rate window.
+    RateLimited,
+    /// The global concurrent-session cap is reached.
+    ServerBusy,
+    /// This source already has the maximum number of sessions open.
+    SourceBusy,
+}
+
+impl Rejection {
+    /// Stable identifier used in analytics.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Rejection::RateLimited => "rate_limited",
+            Rejection::ServerBusy => "server_busy",
+            Rejection::SourceBusy => "source_busy",
+        }
+    }
+}
+
+/// Key for the connecting client, a salted hash of the address in `SSH_CONNECTION`.
+/// Local runs without sshd all share the `local` key. The std hasher may change between Rust
+/// releases, which only restarts the rate windows of a rebuilt binary.
+pub fn source_key(config: &Config) -> String {
+    let Some(ip) = std::env::var("SSH_CONNECTION")
+        .ok()
+        .and_then(|connection| connection.split_whitespace().next().map(str::to_string))
+    else {
+        return "local".to_string();
+    };
+    let mut hasher = DefaultHasher::new();
+    salt(&config.state_dir).hash(&mut hasher);
+    ip.hash(&mut hasher);
+    format!("{:016x}", hasher.finish())
+}
+
+/// This install's salt, kept in `source_salt` in the state directory and created by whichever
+/// session needs it first. Without a writable state directory the salt only lives as long as the
+/// process, so keys from different sessions never match.
+fn salt(state_dir: &Path) -> [u8; SALT_LEN] {
+    let path = state_dir.join("source_salt");
+    let read = || fs::read(&path).ok()?.try_into().ok();
+    if let Some(salt) = read() {
+        return salt;
+    }
+    let salt: [u8; SALT_LEN] = rand::random();
+    let _ = fs::create_dir_all(state_dir);
+    // Publish with a hard link, which fails if another session got there first, so concurrent
+    // first sessions all end up with the same salt.
+    let temp = state_dir.join(format!("source_salt.{}", analytics::new_session_id()));
+    let mut options = OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    options.mode(0o600);
+    let written = options
+        .open(&temp)
+        .and_then(|mut file| file.write_all(&salt));
+    if written.is_ok() {
+        let _ = fs::hard_link(&temp, &path);
+    }
+    let _ = fs::remove_file(&temp);
+    read().unwrap_or(salt)
+}
+
+/// A connection that passed [`check`]. Keep it until the session has joined the presence
+/// registry; dropping it lets the next connection be checked.
+#[derive(Debug)]
+pub struct Admission {
+    /// `None` if the state directory is unwritable, in which case checks are not serialized.
+    _lock: Option<File>,
+}
+
+/// Record a turned-away connection in the host's syslog (auth facility), so it is logged even with
+/// analytics off. Without a syslog socket, e.g. in local runs or off Unix, it goes to stderr
+/// instead.
+pub fn log_rejection(rejection: Rejection, source: &str) {
+    let message = format!(
+        "ssh-tui[{}]: turned away connection from source {source}: {}",
+        std::process::id(),
+        rejection.name()
+    );
+    if syslog(&message).is_err() {
+        eprintln!("{message}");
+    }
+}
+
+#[cfg(unix)]
+fn syslog(message: &str) -> io::Result<()> {
+    let socket = UnixDatagram::unbound()?;
+    socket.send_to(format!("<37>{message}").as_bytes(), "/dev/log")?;
+    Ok(())
+}
+
+#[cfg(not(unix))]
+fn syslog(_: &str) -> io::Result<()> {
+    Err(io::ErrorKind::Unsupported.into())
+}
+
+/// Check the limits for a connection from `source`, counting it as an attempt.
+pub fn check(config: &Config, source: &str) -> Result<Admission, Rejection> {
+    let lock = lock(&config.state_dir);
+    if config.rate_limit > 0
+        && record_attempt(&config.state_dir.join("ratelimit"), source) > config.rate_limit
+    {
+        return Err(Rejection::RateLimited);
+    }
+    let presence_dir = config.presence_dir();
+    if config.max_sessions > 0 && presence::snapshot(&presence_dir).online >= config.max_sessions {
+        return Err(Rejection::ServerBusy);
+    }
+    if config.max_sessions_per_source > 0
+        && presence::sessions_from(&presence_dir, source) >= config.max_sessions_per_source
+    {
+        return Err(Rejection::SourceBusy);
+    }
+    Ok(Admission { _lock: lock })
+}
+
+/// Wait for exclusive use of the admission lock in `state_dir`.
+fn lock(state_dir: &Path) -> Option<File> {
+    let _ = fs::create_dir_all(state_dir);
+    let file = OpenOptions::new()
+        .create(true)
+        .truncate(false)
+        .write(true)
+        .open(state_dir.join("admission.lock"))
+        .ok()?;
+    file.lock().ok()?;
+    Some(file)
+}
+
+/// Append this attempt to the source's log and return how many attempts fall in the window.
+/// Logs of sources that have been quiet for a whole window are removed along the way. Only
+/// called under the admission lock, so rewriting the log cannot lose a concurrent attempt.
+fn record_attempt(dir: &Path, source: &str) -> usize {
+    let now = unix_millis(SystemTime::now());
+    let cutoff = now.saturating_sub(RATE_WINDOW.as_millis() as u64);
+    let _ = fs::create_dir_all(dir);
+    prune_quiet_sources(dir);
+
+    let path = dir.join(source);
+    let mut attempts: Vec<u64> = fs::read_to_string(&path)
+        .unwrap_or_default()
+        .lines()
+        .filter_map(|line| line.trim().parse().ok())
+        .filter(|&ts| ts >= cutoff)
+        .collect();
+    attempts.push(now);
+    let text: String = attempts.iter().map(|ts| format!("{ts}\n")).collect();
+    let _ = fs::write(&path, text);
+    attempts.len()
+}
+
+fn prune_quiet_sources(dir: &Path) {
+    for (path, quiet) in stale_entries(dir, RATE_WINDOW) {
+        if quiet {
+            let _ = fs::remove_file(path);
+        }
+    }
+}
@@ -0,0 +1,134 @@
+//! Anonymized per-session analytics, appended to a JSON Lines file.
+//!
+//! Every event is written with a single `write` on a file opened in append mode, so concurrent
+//! sessions never interleave partial lines and everything recorded before a crash stays on disk.
+//! No usernames, addresses or typed text are stored; sessions are identified by a random ID.
+
+use std::{
+    fs::{self, File, OpenOptions},
+    io::Write,
+    path::Path,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::session_guard::Finish;
+
+/// Something that happened during a session.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum Event {
+    Connect {
+        term: Option<String>,
+        width: u16,
+        height: u16,
+    },
+    ScreenEntered {
+        screen: String,
+    },
+    ScreenLeft {
+        screen: String,
+        dwell_ms: u64,
+    },
+    /// A bound key was pressed; `key` is the binding, never free text.
+    Key {
+        key: String,
+    },
+    Resize {
+        width: u16,
+        height: u16,
+    },
+    Disconnect {
+        reason: String,
+        duration_ms: u64,
+    },
+    /// The connection was turned away by a rate limit or session cap.
+    Rejected {
+        reason: String,
+    },
+}
+
+/// One line of the log.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Record {
+    /// Milliseconds since the Unix epoch.
+    pub ts: u64,
+    pub session: String,
+    #[serde(flatten)]
+    pub event: Event,
+}
+
+/// Writer for one session's events. A default-constructed recorder discards everything.
+#[derive(Debug, Default)]
+pub struct Analytics {
+    session: String,
+    file: Option<File>,
+    started: Option<SystemTime>,
+}
+
+impl Analytics {
+    /// Open (creating if needed) the log at `path` for the session identified by `session`.
+    ///
+    /// Analytics must never stop someone from viewing the portfolio, so an unwritable path just
+    /// yields a recorder that drops events.
+    pub fn open(path: &Path, session: &str) -> Self {
+        if let Some(parent) = path.parent() {
+            let _ = fs::create_dir_all(parent);
+        }
+        let file = OpenOptions::new().create(true).append(true).open(path).ok();
+        Self {
+            session: session.to_string(),
+            file,
+            started: Some(SystemTime::now()),
+        }
+    }
+
+    pub fn record(&mut self, event: Event) {
+        let Some(file) = &mut self.file else {
+            return;
+        };
+        let record = Record {
+            ts: unix_millis(SystemTime::now()),
+            session: self.session.clone(),
+            event,
+        };
+        if let Ok(mut line) = serde_json::to_string(&record) {
+            line.push('\n');
+            let _ = file.write_all(line.as_bytes());
+        }
+    }
+
+    /// Record that the connection was refused; no session follows, so no disconnect is logged.
+    pub fn reject(&mut self, reason: &str) {
+        self.record(Event::Rejected {
+            reason: reason.to_string(),
+        });
+    }
+}
+
+impl Finish for Analytics {
+    /// Record a disconnect with the session's duration.
+    fn finish(&mut self, reason: &str) {
+        let duration_ms = self
+            .started
+            .and_then(|started| started.elapsed().ok())
+            .map_or(0, |elapsed| elapsed.as_millis() as u64);
+        self.record(Event::Disconnect {
+            reason: reason.to_string(),
+            duration_ms,
+        });
+    }
+}
+
+/// Milliseconds since the Unix epoch.
+pub fn unix_millis(time: SystemTime) -> u64 {
+    time.duration_since(UNIX_EPOCH)
+        .unwrap_or(Duration::ZERO)
+        .as_millis() as u64
+}
+
+/// Random identifier tying together one connection's events and presence entry.
+pub fn new_session_id() -> String {
+    format!("{:016x}", rand::random::<u64>())
+}
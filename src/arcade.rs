@@ -0,0 +1,283 @@
+//! Snake for the Arcade screen, stepped by the app tick, plus the shared high-score table.
+//!
+//! High scores live in `highscores.json` in the state directory, keeping each visitor handle's
+//! best score. The file is rewritten through a temporary file and a rename so concurrent
+//! sessions never observe a half-written table.
+
+use std::{
+    collections::VecDeque,
+    fs,
+    path::{Path, PathBuf},
+    time::SystemTime,
+};
+
+use crossterm::event::{KeyCode, KeyEvent};
+use serde::{Deserialize, Serialize};
+
+use crate::{analytics::unix_millis, input::KeyHandler, state_files::atomic_write};
+
+/// Playfield size in cells; each cell is drawn two columns wide.
+pub const BOARD_WIDTH: u16 = 24;
+pub const BOARD_HEIGHT: u16 = 14;
+/// Entries kept in the high-score table.
+pub const TABLE_SIZE: usize = 10;
+pub const MAX_HANDLE_LEN: usize = 12;
+const START_LENGTH: u16 = 3;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction {
+    fn opposite(self) -> Self {
+        match self {
+            Direction::Up => Direction::Down,
+            Direction::Down => Direction::Up,
+            Direction::Left => Direction::Right,
+            Direction::Right => Direction::Left,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum Phase {
+    #[default]
+    Ready,
+    Playing,
+    Over,
+}
+
+#[derive(Debug, Clone)]
+pub struct Snake {
+    /// Cells from head to tail.
+    pub body: VecDeque<(u16, u16)>,
+    heading: Direction,
+    /// Direction to take on the next step; turns apply once per step so a quick double tap
+    /// cannot reverse the snake into itself.
+    next_heading: Direction,
+    pub food: (u16, u16),
+    pub score: u32,
+    pub phase: Phase,
+}
+
+impl Default for Snake {
+    fn default() -> Self {
+        let y = BOARD_HEIGHT / 2;
+        let body = (0..START_LENGTH)
+            .map(|offset| (START_LENGTH - offset, y))
+            .collect();
+        let mut snake = Self {
+            body,
+            heading: Direction::Right,
+            next_heading: Direction::Right,
+            food: (0, 0),
+            score: 0,
+            phase: Phase::Ready,
+        };
+        snake.place_food();
+        snake
+    }
+}
+
+impl Snake {
+    pub fn turn(&mut self, direction: Direction) {
+        if direction != self.heading.opposite() {
+            self.next_heading = direction;
+        }
+    }
+
+    /// Advance one cell, eating, growing or dying as appropriate.
+    pub fn step(&mut self) {
+        if self.phase != Phase::Playing {
+            return;
+        }
+        self.heading = self.next_heading;
+        let (x, y) = self.body[0];
+        let head = match self.heading {
+            Direction::Up => y.checked_sub(1).map(|y| (x, y)),
+            Direction::Down => (y + 1 < BOARD_HEIGHT).then_some((x, y + 1)),
+            Direction::Left => x.checked_sub(1).map(|x| (x, y)),
+            Direction::Right => (x + 1 < BOARD_WIDTH).then_some((x + 1, y)),
+        };
+        let eating = head == Some(self.food);
+        if !eating {
+            self.body.pop_back();
+        }
+        match head {
+            Some(head) if !self.body.contains(&head) => self.body.push_front(head),
+            _ => {
+                self.phase = Phase::Over;
+                return;
+            }
+        }
+        if eating {
+            self.score += 1;
+            if !self.place_food() {
+                self.phase = Phase::Over;
+            }
+        }
+    }
+
+    /// Drop food on a random free cell; `false` when the board is full.
+    fn place_food(&mut self) -> bool {
+        let free: Vec<(u16, u16)> = (0..BOARD_HEIGHT)
+            .flat_map(|y| (0..BOARD_WIDTH).map(move |x| (x, y)))
+            .filter(|cell| !self.body.contains(cell))
+            .collect();
+        if free.is_empty() {
+            return false;
+        }
+        self.food = free[rand::random_range(0..free.len())];
+        true
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HighScore {
+    pub handle: String,
+    pub score: u32,
+    /// Milliseconds since the Unix epoch.
+    pub ts: u64,
+}
+
+/// The game, the high-score table and the handle being typed after a qualifying run.
+#[derive(Debug, Default)]
+pub struct Arcade {
+    pub game: Snake,
+    pub scores: Vec<HighScore>,
+    /// `Some` while the visitor is entering a handle for the table.
+    pub entry: Option<String>,
+    path: Option<PathBuf>,
+}
+
+impl Arcade {
+    pub fn new(path: PathBuf) -> Self {
+        Self {
+            scores: load_scores(&path),
+            path: Some(path),
+            ..Self::default()
+        }
+    }
+
+    /// Advance the game by one app tick.
+    pub fn tick(&mut self) {
+        let was_playing = self.game.phase == Phase::Playing;
+        self.game.step();
+        if was_playing && self.game.phase == Phase::Over && self.qualifies(self.game.score) {
+            self.entry = Some(String::new());
+        }
+    }
+
+    fn qualifies(&self, score: u32) -> bool {
+        score > 0
+            && (self.scores.len() < TABLE_SIZE
+                || self
+                    .scores
+                    .last()
+                    .is_some_and(|lowest| score > lowest.score))
+    }
+
+    /// Record `handle`'s score, re-reading the table first so other sessions' entries survive.
+    fn submit(&mut self, handle: String) {
+        let Some(path) = &self.path else {
+            return;
+        };
+        let mut scores = load_scores(path);
+        match scores.iter_mut().find(|entry| entry.handle == handle) {
+            Some(entry) if entry.score >= self.game.score => {}
+            Some(entry) => {
+                entry.score = self.game.score;
+                entry.ts = unix_millis(SystemTime::now());
+            }
+            None => scores.push(HighScore {
+                handle,
+                score: self.game.score,
+                ts: unix_millis(SystemTime::now()),
+            }),
+        }
+        sort_scores(&mut scores);
+        let _ = save_scores(path, &scores);
+        self.scores = scores;
+    }
+}
+
+impl KeyHandler for Arcade {
+    type Context = ();
+
+    fn on_key(&mut self, key: KeyEvent, _: &()) -> Option<&'static str> {
+        if let Some(handle) = &mut self.entry {
+            match key.code {
+                KeyCode::Char(c)
+                    if (c.is_ascii_alphanumeric() || c == '_' || c == '-')
+                        && handle.len() < MAX_HANDLE_LEN =>
+                {
+                    handle.push(c);
+                }
+                KeyCode::Backspace => {
+                    handle.pop();
+                }
+                KeyCode::Enter => {
+                    let handle = std::mem::take(handle);
+                    self.entry = None;
+                    if !handle.is_empty() {
+                        self.submit(handle);
+                    }
+                    return Some("arcade-save");
+                }
+                KeyCode::Esc => {
+                    self.entry = None;
+                    return Some("arcade-skip");
+                }
+                _ => {}
+            }
+            // Typed handles are never logged.
+            return Some("arcade-handle");
+        }
+
+        let direction = match key.code {
+            KeyCode::Up | KeyCode::Char('k') | KeyCode::Char('w') => Direction::Up,
+            KeyCode::Down | KeyCode::Char('j') | KeyCode::Char('s') => Direction::Down,
+            KeyCode::Left | KeyCode::Char('h') | KeyCode::Char('a') => Direction::Left,
+            KeyCode::Right | KeyCode::Char('l') | KeyCode::Char('d') => Direction::Right,
+            KeyCode::Char(' ') | KeyCode::Enter => {
+                if self.game.phase == Phase::Playing {
+                    return None;
+                }
+                self.game = Snake {
+                    phase: Phase::Playing,
+                    ..Snake::default()
+                };
+                return Some("arcade-start");
+            }
+            _ => return None,
+        };
+        if self.game.phase != Phase::Playing {
+            return None;
+        }
+        self.game.turn(direction);
+        Some("arcade-steer")
+    }
+}
+
+fn load_scores(path: &Path) -> Vec<HighScore> {
+    let mut scores: Vec<HighScore> = fs::read_to_string(path)
+        .ok()
+        .and_then(|text| serde_json::from_str(&text).ok())
+        .unwrap_or_default();
+    sort_scores(&mut scores);
+    scores
+}
+
+/// Highest first, earlier runs winning ties, trimmed to [`TABLE_SIZE`].
+fn sort_scores(scores: &mut Vec<HighScore>) {
+    scores.sort_by(|a, b| b.score.cmp(&a.score).then(a.ts.cmp(&b.ts)));
+    scores.truncate(TABLE_SIZE);
+}
+
+fn save_scores(path: &Path, scores: &[HighScore]) -> std::io::Result<()> {
+    atomic_write(path, serde_json::to_string_pretty(scores)?.as_bytes())
+}
@@ -0,0 +1,361 @@
+//! ASCII art pieces for the intro screen, loaded from `*.art` files.
+//!
+//! A piece file starts with `key: value` header lines (`label`, `accent`, `tagline`, `loops`)
+//! followed by one or more frames. Each frame begins with a `--- <ticks>` line giving how long
+//! it stays on screen; lines starting with `#` before the first frame are comments. The pieces
+//! in the repo's `art/` directory are compiled in, and files in `SSH_TUI_ART_DIR` override or
+//! extend them by file name without a rebuild.
+
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use color_eyre::{
+    Result,
+    eyre::{WrapErr, bail, eyre},
+};
+use ratatui::style::Color;
+
+use crate::screens::theme::{ACCENT_TEAL, parse_accent};
+
+/// Frame length used when a `---` line does not give one.
+const DEFAULT_FRAME_TICKS: u64 = 4;
+
+const BUILTIN: [(&str, &str); 3] = [
+    ("linux", include_str!("../art/linux.art")),
+    ("neovim", include_str!("../art/neovim.art")),
+    ("terminal", include_str!("../art/terminal.art")),
+];
+
+/// One image of a piece and how many ticks it is shown for.
+#[derive(Debug, Clone)]
+pub struct ArtFrame {
+    pub lines: Vec<String>,
+    pub ticks: u64,
+}
+
+/// A titled piece of intro art with one or more animation frames.
+#[derive(Debug, Clone)]
+pub struct ArtPiece {
+    pub label: String,
+    pub tagline: String,
+    pub accent: Color,
+    /// How many times the frames play before the intro moves on to the next piece.
+    pub loops: u32,
+    pub frames: Vec<ArtFrame>,
+}
+
+impl ArtPiece {
+    /// Tallest frame, so the panel keeps a stable size while the piece animates.
+    pub fn height(&self) -> usize {
+        self.frames
+            .iter()
+            .map(|frame| frame.lines.len())
+            .max()
+            .unwrap_or(0)
+    }
+
+    pub fn parse(source: &str) -> Result<Self> {
+        let mut label = None;
+        let mut tagline = String::new();
+        let mut accent = ACCENT_TEAL;
+        let mut loops = 1;
+        let mut frames: Vec<ArtFrame> = Vec::new();
+
+        for (number, line) in source.lines().enumerate() {
+            let line = line.trim_end_matches('\r');
+            if let Some(rest) = line.strip_prefix("---") {
+                let rest = rest.trim();
+                let ticks = if rest.is_empty() {
+                    DEFAULT_FRAME_TICKS
+                } else {
+                    rest.parse()
+                        .map_err(|_| eyre!("line {}: bad frame length `{rest}`", number + 1))?
+                };
+                frames.push(ArtFrame {
+                    lines: Vec::new(),
+                    ticks: ticks.max(1),
+                });
+                continue;
+            }
+            if let Some(frame) = frames.last_mut() {
+                frame.lines.push(line.to_string());
+                continue;
+            }
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let Some((key, value)) = line.split_once(':') else {
+                bail!("line {}: expected `key: value` header", number + 1);
+            };
+            let value = value.trim();
+            match key.trim() {
+                "label" => label = Some(value.to_string()),
+                "tagline" => tagline = value.to_string(),
+                "accent" => {
+                    accent = parse_accent(value)
+                        .ok_or_else(|| eyre!("line {}: unknown accent `{value}`", number + 1))?
+                }
+                "loops" => {
+                    loops = value
+                        .parse()
+                        .map_err(|_| eyre!("line {}: bad loop count `{value}`", number + 1))?
+                }
+                other => bail!("line {}: unknown header `{other}`", number + 1),
+            }
+        }
+
+        // Trailing blank lines are an editing artifact, not part of the image.
+        for frame in &mut frames {
+            while frame
+                .lines
+                .last()
+                .is_some_and(|line| line.trim().is_empty())
+            {
+                frame.lines.pop();
+            }
+        }
+        // So is a closing `---` with nothing after it.
+        if frames.len() > 1 && frames.last().is_some_and(|frame| frame.lines.is_empty()) {
+            frames.pop();
+        }
+        if frames.is_empty() {
+            bail!("no frames; start one with a `---` line");
+        }
+        Ok(Self {
+            label: label.ok_or_else(|| eyre!("missing `label` header"))?,
+            tagline,
+            accent,
+            loops: loops.max(1),
+            frames,
+        })
+    }
+}
+
+/// Built-in pieces plus any `*.art` files in `dir`, ordered by file name.
+///
+/// Unreadable or malformed files are skipped so a bad upload never takes the intro down.
+pub fn load(dir: Option<&Path>) -> Vec<ArtPiece> {
+    let mut pieces: BTreeMap<String, ArtPiece> = BUILTIN
+        .iter()
+        .filter_map(|(name, source)| Some((name.to_string(), ArtPiece::parse(source).ok()?)))
+        .collect();
+
+    if let Some(dir) = dir {
+        for path in art_files(dir) {
+            let Some(name) = path.file_stem().and_then(|stem| stem.to_str()) else {
+                continue;
+            };
+            if let Ok(piece) = read_piece(&path) {
+                pieces.insert(name.to_string(), piece);
+            }
+        }
+    }
+    pieces.into_values().collect()
+}
+
+fn read_piece(path: &Path) -> Result<ArtPiece> {
+    let source =
+        fs::read_to_string(path).wrap_err_with(|| format!("reading {}", path.display()))?;
+    ArtPiece::parse(&source).wrap_err_with(|| format!("parsing {}", path.display()))
+}
+
+fn art_files(dir: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    entries
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == "art"))
+        .collect()
+}
+
+/// Which piece and frame the intro is showing, advanced once per app tick.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Playback {
+    pub piece: usize,
+    pub frame: usize,
+    frame_ticks: u64,
+    loops_done: u32,
+}
+
+impl Playback {
+    /// Advance one tick, moving to the next frame, loop or piece as durations elapse.
+    pub fn advance(&mut self, pieces: &[ArtPiece]) {
+        let Some(piece) = pieces.get(self.piece) else {
+            *self = Self::default();
+            return;
+        };
+        let Some(frame) = piece.frames.get(self.frame) else {
+            self.frame = 0;
+            self.frame_ticks = 0;
+            return;
+        };
+
+        self.frame_ticks += 1;
+        if self.frame_ticks < frame.ticks {
+            return;
+        }
+        self.frame_ticks = 0;
+        self.frame += 1;
+        if self.frame < piece.frames.len() {
+            return;
+        }
+        self.frame = 0;
+        self.loops_done += 1;
+        if self.loops_done >= piece.loops {
+            self.loops_done = 0;
+            self.piece = (self.piece + 1) % pieces.len();
+        }
+    }
+
+    /// Restart the current frame's timer, e.g. while the intro is off screen.
+    pub fn hold(&mut self) {
+        self.frame_ticks = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn error(source: &str) -> String {
+        ArtPiece::parse(source).unwrap_err().to_string()
+    }
+
+    fn piece(frames: &[u64], loops: u32) -> ArtPiece {
+        ArtPiece {
+            label: "test".to_string(),
+            tagline: String::new(),
+            accent: ACCENT_TEAL,
+            loops,
+            frames: frames
+                .iter()
+                .map(|&ticks| ArtFrame {
+                    lines: vec!["x".to_string()],
+                    ticks,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn parses_headers_and_frames() {
+        let piece = ArtPiece::parse(
+            "# comment\r\nlabel: Demo\naccent: gold\nloops: 3\n--- 2\n a\n b\n\n---\n c\n",
+        )
+        .unwrap();
+        assert_eq!(piece.label, "Demo");
+        assert_eq!(piece.loops, 3);
+        assert_eq!(piece.frames.len(), 2);
+        assert_eq!(piece.frames[0].lines, [" a", " b"]);
+        assert_eq!(piece.frames[0].ticks, 2);
+        assert_eq!(piece.frames[1].ticks, DEFAULT_FRAME_TICKS);
+        assert_eq!(piece.height(), 2);
+    }
+
+    #[test]
+    fn rejects_malformed_frame_lengths() {
+        assert_eq!(
+            error("label: Demo\n--- fast\nx"),
+            "line 2: bad frame length `fast`"
+        );
+        assert_eq!(
+            error("label: Demo\n--- -1\nx"),
+            "line 2: bad frame length `-1`"
+        );
+        // Zero would never advance, so it is shown for one tick.
+        let piece = ArtPiece::parse("label: Demo\n--- 0\nx").unwrap();
+        assert_eq!(piece.frames[0].ticks, 1);
+    }
+
+    #[test]
+    fn rejects_files_without_frames_or_label() {
+        assert_eq!(error(""), "no frames; start one with a `---` line");
+        assert_eq!(
+            error("label: Demo\n"),
+            "no frames; start one with a `---` line"
+        );
+        assert_eq!(error("---\nx"), "missing `label` header");
+        assert_eq!(
+            error("label Demo\n---\nx"),
+            "line 1: expected `key: value` header"
+        );
+        assert_eq!(
+            error("label: Demo\nsize: 3\n---\nx"),
+            "line 2: unknown header `size`"
+        );
+    }
+
+    #[test]
+    fn ignores_a_trailing_separator() {
+        let piece = ArtPiece::parse("label: Demo\n--- 1\nx\n--- 1\ny\n---\n\n").unwrap();
+        assert_eq!(piece.frames.len(), 2);
+        assert_eq!(piece.frames[1].lines, ["y"]);
+    }
+
+    #[test]
+    fn builtin_pieces_parse() {
+        for (name, source) in BUILTIN {
+            assert!(ArtPiece::parse(source).is_ok(), "{name}");
+        }
+    }
+
+    #[test]
+    fn files_in_the_art_dir_override_and_extend() {
+        let dir = std::env::temp_dir().join(format!("ssh-tui-art-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("linux.art"), "label: Mine\n---\nx").unwrap();
+        fs::write(dir.join("extra.art"), "label: Extra\n---\ny").unwrap();
+        fs::write(dir.join("broken.art"), "no frames here").unwrap();
+        fs::write(dir.join("notes.txt"), "label: Ignored\n---\nz").unwrap();
+        let labels: Vec<String> = load(Some(&dir))
+            .into_iter()
+            .map(|piece| piece.label)
+            .collect();
+        fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(labels, ["Extra", "Mine", "Neovim", "Terminal"]);
+    }
+
+    #[test]
+    fn playback_steps_frames_then_loops_then_pieces() {
+        let pieces = [piece(&[2, 1], 2), piece(&[1], 1)];
+        let mut playback = Playback::default();
+        let mut seen = Vec::new();
+        for _ in 0..9 {
+            playback.advance(&pieces);
+            seen.push((playback.piece, playback.frame));
+        }
+        assert_eq!(
+            seen,
+            [
+                (0, 0),
+                (0, 1),
+                (0, 0),
+                (0, 0),
+                (0, 1),
+                (1, 0),
+                (0, 0),
+                (0, 0),
+                (0, 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn playback_recovers_from_stale_indices() {
+        let pieces = [piece(&[1], 1)];
+        let mut playback = Playback {
+            piece: 0,
+            frame: 5,
+            ..Playback::default()
+        };
+        playback.advance(&pieces);
+        assert_eq!((playback.piece, playback.frame), (0, 0));
+        playback.piece = 3;
+        playback.advance(&pieces);
+        assert_eq!((playback.piece, playback.frame), (0, 0));
+    }
+}
@@ -0,0 +1,164 @@
+//! Markdown posts for the Blog screen.
+//!
+//! A post is a Markdown file that may start with a front-matter block between `---` lines
+//! holding `title`, `date` (`YYYY-MM-DD`) and `tags` (`[a, b]` or `a, b`). Posts in the repo's
+//! `posts/` directory are compiled in, and `*.md` files in `SSH_TUI_BLOG_DIR` override or extend
+//! them by file name without a rebuild.
+
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crossterm::event::{KeyCode, KeyEvent};
+
+use crate::input::{KeyHandler, Scroll};
+
+/// Stand-in shown until the owner supplies posts through `SSH_TUI_BLOG_DIR`.
+const BUILTIN: [(&str, &str); 1] = [("placeholder", include_str!("../posts/placeholder.md"))];
+
+#[derive(Debug, Clone)]
+pub struct Post {
+    pub title: String,
+    /// `YYYY-MM-DD`, or empty when the front matter has none.
+    pub date: String,
+    pub tags: Vec<String>,
+    /// Markdown after the front matter.
+    pub body: String,
+}
+
+impl Post {
+    /// Parse a post, titling it after `slug` when the front matter has no title.
+    pub fn parse(slug: &str, source: &str) -> Self {
+        let mut post = Self {
+            title: slug.replace(['-', '_'], " "),
+            date: String::new(),
+            tags: Vec::new(),
+            body: source.to_string(),
+        };
+        let Some(rest) = source.strip_prefix("---") else {
+            return post;
+        };
+        let Some((front, body)) = rest.split_once("\n---") else {
+            return post;
+        };
+        for line in front.lines() {
+            let Some((key, value)) = line.split_once(':') else {
+                continue;
+            };
+            let value = value.trim();
+            match key.trim() {
+                "title" => post.title = value.trim_matches('"').to_string(),
+                "date" => post.date = value.to_string(),
+                "tags" => {
+                    post.tags = value
+                        .trim_start_matches('[')
+                        .trim_end_matches(']')
+                        .split(',')
+                        .map(|tag| tag.trim().trim_matches('"').to_string())
+                        .filter(|tag| !tag.is_empty())
+                        .collect();
+                }
+                _ => {}
+            }
+        }
+        // Drop the rest of the closing `---` line.
+        post.body = body
+            .split_once('\n')
+            .map_or("", |(_, body)| body)
+            .to_string();
+        post
+    }
+}
+
+/// Built-in posts plus any `*.md` files in `dir`, newest first.
+pub fn load(dir: Option<&Path>) -> Vec<Post> {
+    let mut posts: BTreeMap<String, Post> = BUILTIN
+        .iter()
+        .map(|(slug, source)| (slug.to_string(), Post::parse(slug, source)))
+        .collect();
+
+    if let Some(dir) = dir {
+        for path in post_files(dir) {
+            let Some(slug) = path.file_stem().and_then(|stem| stem.to_str()) else {
+                continue;
+            };
+            if let Ok(source) = fs::read_to_string(&path) {
+                posts.insert(slug.to_string(), Post::parse(slug, &source));
+            }
+        }
+    }
+    let mut posts: Vec<Post> = posts.into_values().collect();
+    posts.sort_by(|a, b| b.date.cmp(&a.date));
+    posts
+}
+
+fn post_files(dir: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    entries
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == "md"))
+        .collect()
+}
+
+/// Post list selection and reader scroll position.
+#[derive(Debug, Default)]
+pub struct Blog {
+    pub posts: Vec<Post>,
+    pub selected: usize,
+    /// `true` while a post is open in the reader.
+    pub reading: bool,
+    /// Reader position in the open post.
+    pub scroll: Scroll,
+}
+
+impl Blog {
+    pub fn new(posts: Vec<Post>) -> Self {
+        Self {
+            posts,
+            ..Self::default()
+        }
+    }
+
+    pub fn selected_post(&self) -> Option<&Post> {
+        self.posts.get(self.selected)
+    }
+}
+
+impl KeyHandler for Blog {
+    type Context = ();
+
+    fn on_key(&mut self, key: KeyEvent, _: &()) -> Option<&'static str> {
+        if self.reading {
+            if self.scroll.on_key(key, &()).is_some() {
+                return Some("blog-scroll");
+            }
+            return match key.code {
+                KeyCode::Esc | KeyCode::Backspace | KeyCode::Char('h') | KeyCode::Left => {
+                    self.reading = false;
+                    Some("blog-close")
+                }
+                _ => None,
+            };
+        }
+
+        match key.code {
+            KeyCode::Down | KeyCode::Char('j') if !self.posts.is_empty() => {
+                self.selected = (self.selected + 1) % self.posts.len();
+            }
+            KeyCode::Up | KeyCode::Char('k') if !self.posts.is_empty() => {
+                self.selected = (self.selected + self.posts.len() - 1) % self.posts.len();
+            }
+            KeyCode::Enter | KeyCode::Char('l') | KeyCode::Right if !self.posts.is_empty() => {
+                self.reading = true;
+                self.scroll.offset = 0;
+                return Some("blog-open");
+            }
+            _ => return None,
+        }
+        Some("blog-select")
+    }
+}
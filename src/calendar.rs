@@ -0,0 +1,333 @@
+//! Minimal civil-calendar helpers; the app mostly needs day- and month-level dates.
+
+use std::{
+    collections::BTreeSet,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+const MILLIS_PER_DAY: u64 = 86_400_000;
+const MONTH_NAMES: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+/// A proleptic Gregorian calendar date (UTC).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Date {
+    pub year: i32,
+    pub month: u32,
+    pub day: u32,
+}
+
+impl Date {
+    /// Date of the given count of days since 1970-01-01.
+    ///
+    /// Howard Hinnant's `civil_from_days`.
+    pub fn from_unix_days(days: i64) -> Self {
+        let z = days + 719_468;
+        let era = z.div_euclid(146_097);
+        let doe = z.rem_euclid(146_097);
+        let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+        let year = (yoe + era * 400 + i64::from(month <= 2)) as i32;
+        Self { year, month, day }
+    }
+
+    /// Parse an ISO `YYYY-MM-DD` date, ignoring anything after the day.
+    pub fn parse(text: &str) -> Option<Self> {
+        let mut parts = text.get(..10)?.split('-');
+        let year = parts.next()?.parse().ok()?;
+        let month = parts.next()?.parse().ok()?;
+        let day = parts.next()?.parse().ok()?;
+        ((1..=12).contains(&month) && (1..=31).contains(&day)).then_some(Self { year, month, day })
+    }
+
+    /// Days since 1970-01-01; the inverse of [`Date::from_unix_days`].
+    ///
+    /// Howard Hinnant's `days_from_civil`.
+    pub fn unix_days(&self) -> i64 {
+        let year = i64::from(self.year) - i64::from(self.month <= 2);
+        let month = i64::from(self.month);
+        let era = year.div_euclid(400);
+        let yoe = year.rem_euclid(400);
+        let doy = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5
+            + i64::from(self.day)
+            - 1;
+        let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+        era * 146_097 + doe - 719_468
+    }
+
+    /// Day of the week, `0` for Sunday through `6` for Saturday.
+    pub fn weekday(&self) -> u32 {
+        // 1970-01-01 was a Thursday.
+        (self.unix_days() + 4).rem_euclid(7) as u32
+    }
+
+    /// Three-letter English month name.
+    pub fn month_name(&self) -> &'static str {
+        MONTH_NAMES[(self.month as usize).clamp(1, 12) - 1]
+    }
+
+    /// Short `MM-DD` label for chart axes.
+    pub fn short_label(&self) -> String {
+        format!("{:02}-{:02}", self.month, self.day)
+    }
+}
+
+/// Day number (since the Unix epoch) containing the millisecond timestamp `ts`.
+pub fn unix_day(ts: u64) -> i64 {
+    (ts / MILLIS_PER_DAY) as i64
+}
+
+/// `HH:MM:SS` (UTC) of the millisecond timestamp `ts`.
+pub fn time_of_day(ts: u64) -> String {
+    let seconds = ts % MILLIS_PER_DAY / 1000;
+    format!(
+        "{:02}:{:02}:{:02}",
+        seconds / 3600,
+        seconds / 60 % 60,
+        seconds % 60
+    )
+}
+
+/// Today's date (UTC) from the system clock.
+pub fn today() -> Date {
+    let millis = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_millis() as u64);
+    Date::from_unix_days(unix_day(millis))
+}
+
+/// A calendar month, the resolution of career dates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Month {
+    pub year: i32,
+    pub month: u32,
+}
+
+impl Month {
+    pub const fn new(year: i32, month: u32) -> Self {
+        Self { year, month }
+    }
+
+    /// The month containing today's date.
+    pub fn now() -> Self {
+        Self::from(today())
+    }
+
+    /// Months since January of year 0, so months can be compared and subtracted.
+    pub fn index(&self) -> i32 {
+        self.year * 12 + self.month as i32 - 1
+    }
+
+    /// The month at `index`; the inverse of [`Month::index`].
+    pub fn from_index(index: i32) -> Self {
+        Self {
+            year: index.div_euclid(12),
+            month: index.rem_euclid(12) as u32 + 1,
+        }
+    }
+
+    /// Three-letter English month name.
+    pub fn name(&self) -> &'static str {
+        MONTH_NAMES[(self.month as usize).clamp(1, 12) - 1]
+    }
+
+    /// `Jul 2025`.
+    pub fn label(&self) -> String {
+        format!("{} {}", self.name(), self.year)
+    }
+}
+
+impl From<Date> for Month {
+    fn from(date: Date) -> Self {
+        Self {
+            year: date.year,
+            month: date.month,
+        }
+    }
+}
+
+/// A run of whole months, inclusive; `end` is `None` while it is still going.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Period {
+    pub start: Month,
+    pub end: Option<Month>,
+}
+
+impl Period {
+    /// From `start` through `end`.
+    pub const fn between(start: Month, end: Month) -> Self {
+        Self {
+            start,
+            end: Some(end),
+        }
+    }
+
+    /// From `start` to the present.
+    pub const fn since(start: Month) -> Self {
+        Self { start, end: None }
+    }
+
+    /// Just the one month.
+    pub const fn month(month: Month) -> Self {
+        Self::between(month, month)
+    }
+
+    /// Last month of the period, `now` for an ongoing one.
+    pub fn last(&self, now: Month) -> Month {
+        self.end.unwrap_or(now).max(self.start)
+    }
+
+    /// Months covered, counting both ends.
+    pub fn months(&self, now: Month) -> i32 {
+        self.last(now).index() - self.start.index() + 1
+    }
+
+    /// `Jul 2025 - Present`, `Dec 2024 - Jan 2025`, or `Apr 2025` for a single month.
+    pub fn label(&self) -> String {
+        match self.end {
+            None => format!("{} - Present", self.start.label()),
+            Some(end) if end == self.start => self.start.label(),
+            Some(end) => format!("{} - {}", self.start.label(), end.label()),
+        }
+    }
+}
+
+/// Months in at least one of `periods`, counting overlaps once.
+pub fn months_covered(periods: impl IntoIterator<Item = Period>, now: Month) -> usize {
+    let months: BTreeSet<i32> = periods
+        .into_iter()
+        .flat_map(|period| period.start.index()..=period.last(now).index())
+        .collect();
+    months.len()
+}
+
+/// A month count as `1 yr 3 mos`, `2 yrs` or `5 mos`.
+pub fn duration(months: i32) -> String {
+    let plural = |count: i32, one: &str, many: &str| {
+        format!("{count} {}", if count == 1 { one } else { many })
+    };
+    let (years, months) = (months / 12, months % 12);
+    match (years, months) {
+        (0, months) => plural(months.max(1), "mo", "mos"),
+        (years, 0) => plural(years, "yr", "yrs"),
+        (years, months) => format!(
+            "{} {}",
+            plural(years, "yr", "yrs"),
+            plural(months, "mo", "mos")
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: i32, month: u32, day: u32) -> Date {
+        Date { year, month, day }
+    }
+
+    #[test]
+    fn unix_days_round_trip() {
+        // Four centuries either side of the epoch, covering every leap-year rule.
+        for days in -146_097..146_097 {
+            assert_eq!(Date::from_unix_days(days).unix_days(), days);
+        }
+    }
+
+    #[test]
+    fn known_dates() {
+        assert_eq!(Date::from_unix_days(0), date(1970, 1, 1));
+        assert_eq!(Date::from_unix_days(-1), date(1969, 12, 31));
+        assert_eq!(
+            date(2000, 2, 29).unix_days() + 1,
+            date(2000, 3, 1).unix_days()
+        );
+        assert_eq!(
+            date(1900, 2, 28).unix_days() + 1,
+            date(1900, 3, 1).unix_days()
+        );
+        assert_eq!(
+            date(2024, 12, 31).unix_days() + 1,
+            date(2025, 1, 1).unix_days()
+        );
+        assert_eq!(date(1970, 1, 1).weekday(), 4);
+        assert_eq!(date(2000, 1, 1).weekday(), 6);
+    }
+
+    #[test]
+    fn parse_dates() {
+        assert_eq!(Date::parse("2025-01-31"), Some(date(2025, 1, 31)));
+        assert_eq!(Date::parse("2025-12-01T10:00:00Z"), Some(date(2025, 12, 1)));
+        assert_eq!(Date::parse("2025-13-01"), None);
+        assert_eq!(Date::parse("2025-00-10"), None);
+        assert_eq!(Date::parse("2025-1-1"), None);
+        assert_eq!(Date::parse(""), None);
+    }
+
+    #[test]
+    fn month_index_crosses_years() {
+        assert_eq!(
+            Month::new(2024, 12).index() + 1,
+            Month::new(2025, 1).index()
+        );
+        for index in -30..30 {
+            assert_eq!(Month::from_index(index).index(), index);
+        }
+        assert_eq!(Month::from_index(-1), Month::new(-1, 12));
+        assert_eq!(Month::from(date(2025, 1, 31)), Month::new(2025, 1));
+    }
+
+    #[test]
+    fn period_months() {
+        let now = Month::new(2025, 10);
+        let dec_jan = Period::between(Month::new(2024, 12), Month::new(2025, 1));
+        assert_eq!(dec_jan.months(now), 2);
+        assert_eq!(duration(dec_jan.months(now)), "2 mos");
+        assert_eq!(Period::month(Month::new(2024, 3)).months(now), 1);
+        assert_eq!(Period::since(Month::new(2025, 7)).months(now), 4);
+        assert_eq!(Period::since(Month::new(2025, 1)).last(now), now);
+        // An ongoing period that starts after `now` still counts its first month.
+        assert_eq!(Period::since(Month::new(2026, 1)).months(now), 1);
+    }
+
+    #[test]
+    fn period_labels() {
+        let jan = Month::new(2025, 1);
+        assert_eq!(Period::since(jan).label(), "Jan 2025 - Present");
+        assert_eq!(Period::month(jan).label(), "Jan 2025");
+        assert_eq!(
+            Period::between(Month::new(2024, 12), jan).label(),
+            "Dec 2024 - Jan 2025"
+        );
+    }
+
+    #[test]
+    fn durations() {
+        assert_eq!(duration(0), "1 mo");
+        assert_eq!(duration(1), "1 mo");
+        assert_eq!(duration(11), "11 mos");
+        assert_eq!(duration(12), "1 yr");
+        assert_eq!(duration(13), "1 yr 1 mo");
+        assert_eq!(duration(26), "2 yrs 2 mos");
+    }
+
+    #[test]
+    fn months_covered_counts_overlaps_once() {
+        let now = Month::new(2025, 3);
+        let periods = [
+            Period::between(Month::new(2024, 1), Month::new(2024, 3)),
+            Period::between(Month::new(2024, 3), Month::new(2024, 5)),
+            Period::between(Month::new(2024, 12), Month::new(2025, 1)),
+        ];
+        assert_eq!(months_covered(periods, now), 7);
+        assert_eq!(
+            months_covered([Period::since(Month::new(2024, 11))], now),
+            5
+        );
+        assert_eq!(months_covered([], now), 0);
+    }
+}
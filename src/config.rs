@@ -0,0 +1,195 @@
+//! Runtime settings read from `SSH_TUI_*` environment variables.
+//!
+//! The binary is launched by sshd or systemd without arguments, so the environment (set in the
+//! unit file or via `SetEnv` in `sshd_config`) is the one place operators can tune it.
+
+use std::{path::PathBuf, time::Duration};
+
+use crate::screens::transition::TransitionKind;
+use crate::telemetry::{self, SeriesSpec};
+
+#[derive(Debug, Clone)]
+pub struct Config {
+    /// Screen-change effect, or `None` to switch instantly.
+    pub transition: Option<TransitionKind>,
+    pub transition_duration: Duration,
+    /// Disables motion that is not essential to reading the content.
+    pub reduced_motion: bool,
+    /// Directory of extra `*.art` pieces for the intro screen.
+    pub art_dir: Option<PathBuf>,
+    /// Directory of extra `*.md` posts for the Blog screen.
+    pub blog_dir: Option<PathBuf>,
+    /// Directory of per-project source files for the code viewer, one subdirectory per slug.
+    pub snippets_dir: Option<PathBuf>,
+    /// Where sessions keep shared, persistent files such as the analytics log.
+    pub state_dir: PathBuf,
+    /// Record anonymized session analytics to `analytics.jsonl` in the state directory.
+    pub analytics: bool,
+    /// Unlock the Admin screen for every session (for local use, never on the public host).
+    pub admin: bool,
+    /// authorized_keys-style allow-list of keys that unlock the Admin screen.
+    pub admin_keys: PathBuf,
+    /// Concurrent sessions across all visitors; 0 for no limit.
+    pub max_sessions: usize,
+    /// Concurrent sessions from one source address; 0 for no limit.
+    pub max_sessions_per_source: usize,
+    /// Connections per source address per minute; 0 for no limit.
+    pub rate_limit: usize,
+    /// End sessions without a key press for this long.
+    pub idle_timeout: Option<Duration>,
+    /// End sessions this long after they connect.
+    pub max_session: Option<Duration>,
+    /// GitHub login whose activity `refresh-activity` caches.
+    pub github_user: String,
+    /// GraphQL endpoint queried by `refresh-activity`.
+    pub github_api: String,
+    /// Token for the GitHub API; only `refresh-activity` needs it.
+    pub github_token: Option<String>,
+    /// Series on the Telemetry screen, in display order.
+    pub telemetry: Vec<SeriesSpec>,
+    /// Where `serve-metrics` listens; sessions only record metrics while this is set.
+    pub metrics_addr: Option<String>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            transition: Some(TransitionKind::default()),
+            transition_duration: Duration::from_millis(280),
+            reduced_motion: false,
+            art_dir: None,
+            blog_dir: None,
+            snippets_dir: None,
+            state_dir: default_state_dir(),
+            analytics: true,
+            admin: false,
+            admin_keys: PathBuf::from("admin_keys"),
+            max_sessions: 64,
+            max_sessions_per_source: 4,
+            rate_limit: 10,
+            idle_timeout: Some(Duration::from_secs(10 * 60)),
+            max_session: Some(Duration::from_secs(60 * 60)),
+            github_user: "KarneeshkarV".to_string(),
+            github_api: "https://api.github.com/graphql".to_string(),
+            github_token: None,
+            telemetry: telemetry::default_series(),
+            metrics_addr: None,
+        }
+    }
+}
+
+impl Config {
+    /// Build the config from the process environment, falling back to defaults for anything
+    /// unset or unparseable.
+    pub fn from_env() -> Self {
+        let defaults = Self::default();
+        let state_dir = var("SSH_TUI_STATE_DIR")
+            .map(PathBuf::from)
+            .unwrap_or(defaults.state_dir);
+        Self {
+            transition: match var("SSH_TUI_TRANSITION") {
+                Some(value) if value.eq_ignore_ascii_case("none") => None,
+                Some(value) => TransitionKind::parse(&value).or(defaults.transition),
+                None => defaults.transition,
+            },
+            transition_duration: parse_var("SSH_TUI_TRANSITION_MS")
+                .map(Duration::from_millis)
+                .unwrap_or(defaults.transition_duration),
+            reduced_motion: flag("SSH_TUI_REDUCED_MOTION").unwrap_or(defaults.reduced_motion),
+            art_dir: var("SSH_TUI_ART_DIR").map(PathBuf::from),
+            blog_dir: var("SSH_TUI_BLOG_DIR").map(PathBuf::from),
+            snippets_dir: var("SSH_TUI_SNIPPETS_DIR").map(PathBuf::from),
+            analytics: flag("SSH_TUI_ANALYTICS").unwrap_or(defaults.analytics),
+            admin: flag("SSH_TUI_ADMIN").unwrap_or(defaults.admin),
+            admin_keys: var("SSH_TUI_ADMIN_KEYS")
+                .map(PathBuf::from)
+                .unwrap_or_else(|| state_dir.join(&defaults.admin_keys)),
+            max_sessions: parse_var("SSH_TUI_MAX_SESSIONS").unwrap_or(defaults.max_sessions),
+            max_sessions_per_source: parse_var("SSH_TUI_MAX_SESSIONS_PER_IP")
+                .unwrap_or(defaults.max_sessions_per_source),
+            rate_limit: parse_var("SSH_TUI_RATE_LIMIT").unwrap_or(defaults.rate_limit),
+            idle_timeout: minutes("SSH_TUI_IDLE_TIMEOUT_MINS").unwrap_or(defaults.idle_timeout),
+            max_session: minutes("SSH_TUI_MAX_SESSION_MINS").unwrap_or(defaults.max_session),
+            github_user: var("SSH_TUI_GITHUB_USER").unwrap_or(defaults.github_user),
+            github_api: var("SSH_TUI_GITHUB_API").unwrap_or(defaults.github_api),
+            github_token: var("SSH_TUI_GITHUB_TOKEN"),
+            telemetry: var("SSH_TUI_TELEMETRY")
+                .map(|spec| telemetry::parse_series(&spec))
+                .filter(|series| !series.is_empty())
+                .unwrap_or(defaults.telemetry),
+            metrics_addr: var("SSH_TUI_METRICS_ADDR"),
+            state_dir,
+        }
+    }
+
+    /// The analytics log, if analytics are enabled.
+    pub fn analytics_path(&self) -> Option<PathBuf> {
+        self.analytics
+            .then(|| self.state_dir.join("analytics.jsonl"))
+    }
+
+    /// Registry of live sessions shared by every connection's process.
+    pub fn presence_dir(&self) -> PathBuf {
+        self.state_dir.join("presence")
+    }
+
+    /// Per-session files read by `serve-metrics`, if metrics are enabled.
+    pub fn metrics_dir(&self) -> Option<PathBuf> {
+        self.metrics_addr
+            .is_some()
+            .then(|| self.state_dir.join("metrics"))
+    }
+
+    /// Arcade high-score table shared by all sessions.
+    pub fn highscores_path(&self) -> PathBuf {
+        self.state_dir.join("highscores.json")
+    }
+
+    /// GitHub activity cache written by `refresh-activity`.
+    pub fn activity_path(&self) -> PathBuf {
+        self.state_dir.join("activity.json")
+    }
+
+    /// The transition to play on a screen change, honoring reduced motion.
+    pub fn active_transition(&self) -> Option<TransitionKind> {
+        if self.reduced_motion || self.transition_duration.is_zero() {
+            None
+        } else {
+            self.transition
+        }
+    }
+}
+
+/// `$XDG_STATE_HOME/ssh-tui-portfolio`, falling back to `~/.local/state/ssh-tui-portfolio`, so
+/// state never lands in whatever directory the binary was started from.
+fn default_state_dir() -> PathBuf {
+    let base = var("XDG_STATE_HOME").map(PathBuf::from).or_else(|| {
+        std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".local").join("state"))
+    });
+    base.unwrap_or_else(|| PathBuf::from("."))
+        .join("ssh-tui-portfolio")
+}
+
+fn var(name: &str) -> Option<String> {
+    std::env::var(name)
+        .ok()
+        .filter(|value| !value.trim().is_empty())
+}
+
+fn parse_var<T: std::str::FromStr>(name: &str) -> Option<T> {
+    var(name)?.trim().parse().ok()
+}
+
+/// A whole number of minutes, where `0` means no limit.
+fn minutes(name: &str) -> Option<Option<Duration>> {
+    let minutes: u64 = parse_var(name)?;
+    Some((minutes > 0).then(|| Duration::from_secs(minutes * 60)))
+}
+
+fn flag(name: &str) -> Option<bool> {
+    match var(name)?.trim().to_ascii_lowercase().as_str() {
+        "1" | "true" | "yes" | "on" => Some(true),
+        "0" | "false" | "no" | "off" => Some(false),
+        _ => None,
+    }
+}
@@ -0,0 +1,329 @@
+//! Portfolio content shared by the screens and the pseudo-shell.
+
+use ratatui::style::Color;
+
+use crate::calendar::{self, Month, Period};
+use crate::screens::theme::*;
+
+pub const NAME: &str = "Karneeshkar V";
+pub const TAGLINE: &str =
+    "Software engineer crafting cloud-ready systems, AI automations, and human-centered tools.";
+pub const EMAIL: &str = "karneeshkar01@gmail.com";
+pub const GITHUB: &str = "github.com/KarneeshkarV";
+pub const LINKEDIN: &str = "linkedin.com/in/karneeshkar-velmurugan/";
+
+pub struct Experience {
+    /// Path component used by the shell, e.g. `cat experience/visteon`.
+    pub slug: &'static str,
+    pub company: &'static str,
+    pub role: &'static str,
+    pub period: Period,
+    pub highlights: [&'static str; 2],
+    pub accent: Color,
+}
+
+/// Listed roughly newest first; use [`recent_first`] for a strict order.
+pub const EXPERIENCE: &[Experience] = &[
+    Experience {
+        slug: "2cents",
+        company: "2Cents Capital",
+        role: "Tech Lead",
+        period: Period::since(Month::new(2025, 7)),
+        highlights: [
+            "Led and scaled cross-functional Mobile and AI teams (10+ engineers) to deliver high-impact features with 95% on-time release rate.",
+            "Published a fully responsive investment application on both iOS App Store and Google Play, achieving <0.5s cold-start time.",
+        ],
+        accent: ACCENT_GOLD,
+    },
+    Experience {
+        slug: "visteon",
+        company: "Visteon",
+        role: "Embedded Systems Developer",
+        period: Period::since(Month::new(2025, 1)),
+        highlights: [
+            "Optimized real-time automobile dashboards, cutting processing latency by 25%.",
+            "Engineered fault-tolerant microcontroller code and 95% accurate diagnostics.",
+        ],
+        accent: ACCENT_GOLD,
+    },
+    Experience {
+        slug: "upwork",
+        company: "UpWork",
+        role: "Freelance Developer",
+        period: Period::between(Month::new(2024, 11), Month::new(2025, 1)),
+        highlights: [
+            "Delivered AI-powered RAG legal solutions with 92% accuracy and IoT firmware boosts.",
+            "Completed 5+ engagements maintaining a 4.9/5 satisfaction score.",
+        ],
+        accent: ACCENT_TEAL,
+    },
+    Experience {
+        slug: "pg",
+        company: "Procter & Gamble",
+        role: "IoT Developer & Automation Intern",
+        period: Period::between(Month::new(2024, 12), Month::new(2025, 1)),
+        highlights: [
+            "Rolled out IIoT monitoring across two lines capturing 200K+ daily data points.",
+            "Automated quality checks trimming manual inspection time by 15%.",
+        ],
+        accent: ACCENT_BLUE,
+    },
+    Experience {
+        slug: "intellect",
+        company: "Intellect Design Arena Ltd",
+        role: "Software Intern",
+        period: Period::between(Month::new(2024, 9), Month::new(2024, 12)),
+        highlights: [
+            "Built PHP-based financial sites with 10% faster loads and managed AWS uptime to 99.9%.",
+            "Crafted analytics dashboards for real-time engagement insights.",
+        ],
+        accent: ACCENT_VIOLET,
+    },
+    Experience {
+        slug: "tynatech",
+        company: "TYNATECH Ingenious Pvt Ltd",
+        role: "IoT & LoRaWAN Developer",
+        period: Period::between(Month::new(2024, 5), Month::new(2024, 6)),
+        highlights: [
+            "Integrated 10+ LoRaWAN devices via REST APIs and MQTT visualizations handling 20 msg/s.",
+            "Boosted UART-LoRaWAN throughput by 10% through buffer tuning.",
+        ],
+        accent: ACCENT_GOLD,
+    },
+    Experience {
+        slug: "hal",
+        company: "Hindustan Aeronautics Limited",
+        role: "R&D Intern",
+        period: Period::between(Month::new(2024, 7), Month::new(2024, 8)),
+        highlights: [
+            "Optimized autopilot algorithms reducing computational overhead by 8%.",
+            "Delivered C++ tooling validating 40+ control system scenarios.",
+        ],
+        accent: ACCENT_TEAL,
+    },
+];
+
+pub struct Project {
+    /// Path component used by the shell, e.g. `cat projects/vr-gloves`.
+    pub slug: &'static str,
+    pub name: &'static str,
+    /// When it was built; a single month for most.
+    pub period: Period,
+    pub summary: &'static str,
+    pub stack: &'static str,
+    pub accent: Color,
+}
+
+pub const PROJECTS: &[Project] = &[
+    Project {
+        slug: "llama-code-reasoning",
+        name: "Fine-Tuning LLaMA 3.1 8B for Code Reasoning",
+        period: Period::month(Month::new(2025, 4)),
+        summary: "Applied GRPO reinforcement learning with Gemma3 rewards to elevate code reasoning; released model & dataset.",
+        stack: "LLaMA 3.1 8B · Gemma3 · Python · Hugging Face · DigitalOcean",
+        accent: ACCENT_GOLD,
+    },
+    Project {
+        slug: "vr-gloves",
+        name: "Multipurpose VR Gloves with Gesture Recognition",
+        period: Period::month(Month::new(2024, 9)),
+        summary: "Captured 15+ gestures with 92% accuracy and sub-20ms wireless latency for immersive control.",
+        stack: "Unity · C# · Python · ESP32 · Flex Sensors · IMU",
+        accent: ACCENT_TEAL,
+    },
+    Project {
+        slug: "instagram-bot",
+        name: "Instagram Automation Bot",
+        period: Period::month(Month::new(2024, 12)),
+        summary: "Handled 1000+ daily interactions with 99.5% uptime, using LLM sentiment analysis at 88% accuracy.",
+        stack: "Python · Meta Graph API · FastAPI · Redis · GPT Models · AWS",
+        accent: ACCENT_VIOLET,
+    },
+    Project {
+        slug: "macaque-deterrent",
+        name: "Smart Rhesus Macaque Deterrent System",
+        period: Period::month(Month::new(2024, 3)),
+        summary: "Deployed edge vision alerts with 94% detection accuracy, cutting campus intrusions by 90%.",
+        stack: "Raspberry Pi · Jetson Nano · PyTorch · Computer Vision · Ultrasonic Emitters",
+        accent: ACCENT_BLUE,
+    },
+    Project {
+        slug: "jump-height",
+        name: "Wearable Jump Height Measurement Device",
+        period: Period::month(Month::new(2024, 3)),
+        summary: "Achieved ±1 cm accuracy with Kalman-filtered motion data and actionable training analytics.",
+        stack: "ESP32 · MPU6050 · Flask · SQLite",
+        accent: ACCENT_GOLD,
+    },
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SkillCategory {
+    Languages,
+    Cloud,
+}
+
+impl SkillCategory {
+    pub const ALL: [SkillCategory; 2] = [SkillCategory::Languages, SkillCategory::Cloud];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            SkillCategory::Languages => "Languages",
+            SkillCategory::Cloud => "Cloud",
+        }
+    }
+
+    /// Color of the category's branch in the expertise tree.
+    pub fn accent(self) -> Color {
+        match self {
+            SkillCategory::Languages => ACCENT_TEAL,
+            SkillCategory::Cloud => ACCENT_VIOLET,
+        }
+    }
+
+    /// Categories with at least one entry in [`SKILLS`], in the order of [`SkillCategory::ALL`].
+    pub fn in_use() -> Vec<SkillCategory> {
+        Self::ALL
+            .into_iter()
+            .filter(|&category| SKILLS.iter().any(|skill| skill.category == category))
+            .collect()
+    }
+}
+
+pub struct Skill {
+    pub name: &'static str,
+    /// Self-assessed proficiency, 0–100.
+    pub level: u16,
+    /// Years of regular use, if the owner has filled it in.
+    pub years: Option<u8>,
+    pub category: SkillCategory,
+    pub accent: Color,
+}
+
+/// Grouped by category, strongest first within each.
+pub const SKILLS: &[Skill] = &[
+    Skill {
+        name: "C++",
+        level: 90,
+        years: None,
+        category: SkillCategory::Languages,
+        accent: ACCENT_TEAL,
+    },
+    Skill {
+        name: "Python",
+        level: 85,
+        years: None,
+        category: SkillCategory::Languages,
+        accent: ACCENT_GOLD,
+    },
+    Skill {
+        name: "Rust",
+        level: 80,
+        years: None,
+        category: SkillCategory::Languages,
+        accent: ACCENT_VIOLET,
+    },
+    Skill {
+        name: "JavaScript",
+        level: 75,
+        years: None,
+        category: SkillCategory::Languages,
+        accent: ACCENT_BLUE,
+    },
+    Skill {
+        name: "Go",
+        level: 65,
+        years: None,
+        category: SkillCategory::Languages,
+        accent: ACCENT_GREEN,
+    },
+    Skill {
+        name: "Terraform",
+        level: 60,
+        years: None,
+        category: SkillCategory::Cloud,
+        accent: ACCENT_RED,
+    },
+];
+
+impl Skill {
+    /// Experience entries whose role or highlights name this skill, newest first, with their
+    /// index into [`EXPERIENCE`].
+    pub fn experience(&self) -> impl Iterator<Item = (usize, &'static Experience)> {
+        recent_first(EXPERIENCE, |entry| entry.period)
+            .into_iter()
+            .map(|index| (index, &EXPERIENCE[index]))
+            .filter(|(_, entry)| {
+                self.named_in(entry.role) || entry.highlights.iter().any(|text| self.named_in(text))
+            })
+    }
+
+    /// Projects whose stack names this skill, newest first, with their index into [`PROJECTS`].
+    pub fn projects(&self) -> impl Iterator<Item = (usize, &'static Project)> {
+        recent_first(PROJECTS, |project| project.period)
+            .into_iter()
+            .map(|index| (index, &PROJECTS[index]))
+            .filter(|(_, project)| self.named_in(project.stack))
+    }
+
+    /// Whether `text` mentions the skill as a whole word, so "Go" is not found in "Google".
+    fn named_in(&self, text: &str) -> bool {
+        let is_word = |c: Option<char>| c.is_some_and(char::is_alphanumeric);
+        text.match_indices(self.name).any(|(at, name)| {
+            !is_word(text[..at].chars().next_back())
+                && !is_word(text[at + name.len()..].chars().next())
+        })
+    }
+}
+
+/// Indices of `items`, latest start first and then latest end; ties keep their listed order.
+pub fn recent_first<T>(items: &[T], period: impl Fn(&T) -> Period) -> Vec<usize> {
+    let now = Month::now();
+    let mut order: Vec<usize> = (0..items.len()).collect();
+    order.sort_by_key(|&index| {
+        let period = period(&items[index]);
+        std::cmp::Reverse((period.start, period.last(now)))
+    });
+    order
+}
+
+/// Months spent in at least one role, counting overlapping roles once.
+pub fn experience_months(now: Month) -> usize {
+    calendar::months_covered(EXPERIENCE.iter().map(|entry| entry.period), now)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn skill(name: &str) -> &'static Skill {
+        SKILLS.iter().find(|skill| skill.name == name).unwrap()
+    }
+
+    #[test]
+    fn skills_match_whole_words_only() {
+        let go = skill("Go");
+        assert!(go.named_in("Go · Redis"));
+        assert!(go.named_in("written in Go."));
+        assert!(!go.named_in("Google Play"));
+        assert!(!go.named_in("Gophers"));
+        assert!(skill("C++").named_in("Delivered C++ tooling"));
+    }
+
+    #[test]
+    fn skills_link_to_the_stacks_and_roles_that_name_them() {
+        let projects: Vec<&str> = skill("Python")
+            .projects()
+            .map(|(_, project)| project.slug)
+            .collect();
+        assert_eq!(
+            projects,
+            ["llama-code-reasoning", "instagram-bot", "vr-gloves"]
+        );
+        let experience: Vec<&str> = skill("C++")
+            .experience()
+            .map(|(_, entry)| entry.slug)
+            .collect();
+        assert_eq!(experience, ["hal"]);
+    }
+}
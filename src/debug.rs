@@ -0,0 +1,125 @@
+//! Frame timing and render statistics behind the F12 debug overlay.
+//!
+//! Timings are kept for every session so the overlay has history the moment it is opened; the
+//! per-frame buffer diff is only done while it is visible.
+
+use std::{
+    collections::VecDeque,
+    time::{Duration, Instant},
+};
+
+use ratatui::buffer::Buffer;
+
+use crate::ring::Ring;
+
+/// Frames kept for the average and worst render times.
+const FRAME_WINDOW: usize = 60;
+/// Ticks kept for the measured tick rate.
+const TICK_WINDOW: usize = 25;
+
+/// Colors the client terminal advertises, from `COLORTERM` and `TERM`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ColorDepth {
+    TrueColor,
+    Ansi256,
+    #[default]
+    Ansi16,
+}
+
+impl ColorDepth {
+    pub fn detect() -> Self {
+        let colorterm = std::env::var("COLORTERM").unwrap_or_default();
+        let term = std::env::var("TERM").unwrap_or_default();
+        if matches!(colorterm.as_str(), "truecolor" | "24bit") {
+            Self::TrueColor
+        } else if term.contains("256color") {
+            Self::Ansi256
+        } else {
+            Self::Ansi16
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::TrueColor => "24-bit",
+            Self::Ansi256 => "256 colors",
+            Self::Ansi16 => "16 colors",
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct FrameStats {
+    pub visible: bool,
+    pub color_depth: ColorDepth,
+    /// Time to render and write each recent frame, oldest first
+    frame_times: Ring<Duration>,
+    /// Cells that differed from the previous frame, while visible
+    pub cells_changed: usize,
+    /// Last frame drawn while visible, to diff the next one against
+    previous: Option<Buffer>,
+    /// When each recent tick fired, oldest first
+    ticks: VecDeque<Instant>,
+}
+
+impl FrameStats {
+    pub fn new() -> Self {
+        Self {
+            color_depth: ColorDepth::detect(),
+            frame_times: Ring::new(FRAME_WINDOW),
+            ..Self::default()
+        }
+    }
+
+    pub fn toggle(&mut self) {
+        self.visible = !self.visible;
+        self.previous = None;
+    }
+
+    /// Note a drawn frame and how long it took.
+    pub fn observe_frame(&mut self, elapsed: Duration, buffer: &Buffer) {
+        self.frame_times.push(elapsed);
+        if !self.visible {
+            return;
+        }
+        self.cells_changed = match &self.previous {
+            Some(previous) if previous.area == buffer.area => previous.diff(buffer).len(),
+            // First frame, or the terminal was resized: everything is redrawn.
+            _ => buffer.content.len(),
+        };
+        self.previous = Some(buffer.clone());
+    }
+
+    pub fn observe_tick(&mut self) {
+        if self.ticks.len() == TICK_WINDOW {
+            self.ticks.pop_front();
+        }
+        self.ticks.push_back(Instant::now());
+    }
+
+    pub fn last_frame(&self) -> Duration {
+        self.frame_times.last().unwrap_or_default()
+    }
+
+    pub fn mean_frame(&self) -> Duration {
+        let times = self.frame_times.as_slice();
+        let total: Duration = times.iter().sum();
+        total / times.len().max(1) as u32
+    }
+
+    pub fn worst_frame(&self) -> Duration {
+        self.frame_times
+            .as_slice()
+            .iter()
+            .max()
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Ticks per second over the last few ticks, once there are at least two.
+    pub fn tick_rate(&self) -> Option<f64> {
+        let (first, last) = (self.ticks.front()?, self.ticks.back()?);
+        let span = last.duration_since(*first).as_secs_f64();
+        (span > 0.0).then(|| (self.ticks.len() - 1) as f64 / span)
+    }
+}
@@ -0,0 +1,752 @@
+//! A small, hand-rolled syntax highlighter for the languages shown in project snippets and
+//! blog code blocks.
+//!
+//! It works line by line and only knows enough of each grammar to tell keywords, types,
+//! literals and comments apart; block comments (nested in Rust), Rust raw strings and Python
+//! triple-quoted strings carry over between lines. Colors are chosen by the screens via [`crate::screens::theme::token_style`].
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Language {
+    Rust,
+    Python,
+    Cpp,
+    CSharp,
+    Go,
+    JavaScript,
+    Plain,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Token {
+    Plain,
+    Keyword,
+    Type,
+    Function,
+    String,
+    Number,
+    Comment,
+    /// Attributes, decorators, macros and preprocessor lines.
+    Attribute,
+    Punctuation,
+}
+
+const RUST_KEYWORDS: &[&str] = &[
+    "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum", "extern",
+    "false", "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub",
+    "ref", "return", "self", "Self", "static", "struct", "super", "trait", "true", "type",
+    "unsafe", "use", "where", "while",
+];
+const RUST_TYPES: &[&str] = &[
+    "bool", "char", "f32", "f64", "i8", "i16", "i32", "i64", "i128", "isize", "str", "u8", "u16",
+    "u32", "u64", "u128", "usize",
+];
+const PYTHON_KEYWORDS: &[&str] = &[
+    "False", "None", "True", "and", "as", "assert", "async", "await", "break", "class", "continue",
+    "def", "del", "elif", "else", "except", "finally", "for", "from", "global", "if", "import",
+    "in", "is", "lambda", "nonlocal", "not", "or", "pass", "raise", "return", "try", "while",
+    "with", "yield",
+];
+const PYTHON_TYPES: &[&str] = &[
+    "bool", "bytes", "dict", "float", "int", "list", "object", "set", "str", "tuple",
+];
+const CPP_KEYWORDS: &[&str] = &[
+    "auto",
+    "break",
+    "case",
+    "catch",
+    "class",
+    "const",
+    "constexpr",
+    "continue",
+    "default",
+    "delete",
+    "do",
+    "else",
+    "enum",
+    "explicit",
+    "false",
+    "for",
+    "friend",
+    "if",
+    "inline",
+    "namespace",
+    "new",
+    "noexcept",
+    "nullptr",
+    "operator",
+    "override",
+    "private",
+    "protected",
+    "public",
+    "reinterpret_cast",
+    "return",
+    "sizeof",
+    "static",
+    "static_cast",
+    "struct",
+    "switch",
+    "template",
+    "this",
+    "throw",
+    "true",
+    "try",
+    "typename",
+    "using",
+    "virtual",
+    "volatile",
+    "while",
+];
+const CPP_TYPES: &[&str] = &[
+    "bool", "char", "double", "float", "int", "int8_t", "int16_t", "int32_t", "int64_t", "long",
+    "short", "size_t", "uint8_t", "uint16_t", "uint32_t", "uint64_t", "unsigned", "void",
+];
+const CSHARP_KEYWORDS: &[&str] = &[
+    "abstract",
+    "as",
+    "async",
+    "await",
+    "base",
+    "break",
+    "case",
+    "catch",
+    "class",
+    "const",
+    "continue",
+    "default",
+    "else",
+    "enum",
+    "false",
+    "finally",
+    "for",
+    "foreach",
+    "get",
+    "if",
+    "in",
+    "interface",
+    "internal",
+    "is",
+    "namespace",
+    "new",
+    "null",
+    "out",
+    "override",
+    "private",
+    "protected",
+    "public",
+    "readonly",
+    "ref",
+    "return",
+    "sealed",
+    "set",
+    "static",
+    "struct",
+    "switch",
+    "this",
+    "throw",
+    "true",
+    "try",
+    "using",
+    "var",
+    "virtual",
+    "while",
+];
+const CSHARP_TYPES: &[&str] = &[
+    "bool", "byte", "char", "decimal", "double", "float", "int", "long", "object", "short",
+    "string", "uint", "ulong", "void",
+];
+const GO_KEYWORDS: &[&str] = &[
+    "break",
+    "case",
+    "chan",
+    "const",
+    "continue",
+    "default",
+    "defer",
+    "else",
+    "false",
+    "for",
+    "func",
+    "go",
+    "if",
+    "import",
+    "interface",
+    "map",
+    "nil",
+    "package",
+    "range",
+    "return",
+    "select",
+    "struct",
+    "switch",
+    "true",
+    "type",
+    "var",
+];
+const GO_TYPES: &[&str] = &[
+    "any", "bool", "byte", "error", "float32", "float64", "int", "int32", "int64", "rune",
+    "string", "uint", "uint8", "uint32", "uint64",
+];
+const JAVASCRIPT_KEYWORDS: &[&str] = &[
+    "async",
+    "await",
+    "break",
+    "case",
+    "catch",
+    "class",
+    "const",
+    "continue",
+    "default",
+    "delete",
+    "else",
+    "export",
+    "extends",
+    "false",
+    "finally",
+    "for",
+    "from",
+    "function",
+    "if",
+    "import",
+    "in",
+    "instanceof",
+    "let",
+    "new",
+    "null",
+    "of",
+    "return",
+    "switch",
+    "this",
+    "throw",
+    "true",
+    "try",
+    "typeof",
+    "undefined",
+    "var",
+    "while",
+    "yield",
+];
+
+impl Language {
+    /// The language of a file, from its extension.
+    pub fn from_path(path: &str) -> Self {
+        let extension = path.rsplit_once('.').map_or("", |(_, extension)| extension);
+        Self::from_name(extension)
+    }
+
+    /// The language named by a file extension or a Markdown code fence label.
+    pub fn from_name(name: &str) -> Self {
+        match name.trim().to_ascii_lowercase().as_str() {
+            "rs" | "rust" => Self::Rust,
+            "py" | "python" => Self::Python,
+            "c" | "h" | "cc" | "cpp" | "cxx" | "hpp" | "c++" | "ino" | "arduino" => Self::Cpp,
+            "cs" | "csharp" | "c#" => Self::CSharp,
+            "go" | "golang" => Self::Go,
+            "js" | "javascript" | "ts" | "typescript" | "jsx" | "tsx" => Self::JavaScript,
+            _ => Self::Plain,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Rust => "Rust",
+            Self::Python => "Python",
+            Self::Cpp => "C++",
+            Self::CSharp => "C#",
+            Self::Go => "Go",
+            Self::JavaScript => "JavaScript",
+            Self::Plain => "Text",
+        }
+    }
+
+    fn keywords(&self) -> &'static [&'static str] {
+        match self {
+            Self::Rust => RUST_KEYWORDS,
+            Self::Python => PYTHON_KEYWORDS,
+            Self::Cpp => CPP_KEYWORDS,
+            Self::CSharp => CSHARP_KEYWORDS,
+            Self::Go => GO_KEYWORDS,
+            Self::JavaScript => JAVASCRIPT_KEYWORDS,
+            Self::Plain => &[],
+        }
+    }
+
+    fn types(&self) -> &'static [&'static str] {
+        match self {
+            Self::Rust => RUST_TYPES,
+            Self::Python => PYTHON_TYPES,
+            Self::Cpp => CPP_TYPES,
+            Self::CSharp => CSHARP_TYPES,
+            Self::Go => GO_TYPES,
+            Self::JavaScript | Self::Plain => &[],
+        }
+    }
+
+    fn line_comment(&self) -> Option<&'static str> {
+        match self {
+            Self::Python => Some("#"),
+            Self::Plain => None,
+            _ => Some("//"),
+        }
+    }
+
+    fn has_block_comments(&self) -> bool {
+        !matches!(self, Self::Python | Self::Plain)
+    }
+}
+
+/// What an unfinished line leaves open for the next one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Carry {
+    Nothing,
+    /// Block comments open at this depth; only Rust's nest deeper than one.
+    BlockComment(usize),
+    /// A Python triple-quoted string opened with this quote character.
+    TripleString(char),
+    /// A Rust raw string closed by `"` and this many `#`.
+    RawString(usize),
+}
+
+/// Split `source` into lines of `(token, text)` runs.
+pub fn highlight(source: &str, language: Language) -> Vec<Vec<(Token, String)>> {
+    let mut carry = Carry::Nothing;
+    source
+        .lines()
+        .map(|line| {
+            let mut runs = Runs::default();
+            carry = highlight_line(line, language, carry, &mut runs);
+            runs.0
+        })
+        .collect()
+}
+
+/// Adjacent text with the same token, merged as it is pushed.
+#[derive(Default)]
+struct Runs(Vec<(Token, String)>);
+
+impl Runs {
+    fn push(&mut self, token: Token, chars: &[char]) {
+        if chars.is_empty() {
+            return;
+        }
+        match self.0.last_mut() {
+            Some((last, text)) if *last == token => text.extend(chars),
+            _ => self.0.push((token, chars.iter().collect())),
+        }
+    }
+}
+
+fn starts_with(chars: &[char], at: usize, pattern: &str) -> bool {
+    (at..)
+        .zip(pattern.chars())
+        .all(|(index, expected)| chars.get(index) == Some(&expected))
+}
+
+/// Index just past the first `pattern` at or after `from`, if any.
+fn find(chars: &[char], from: usize, pattern: &str) -> Option<usize> {
+    (from..chars.len())
+        .find(|&index| starts_with(chars, index, pattern))
+        .map(|index| index + pattern.chars().count())
+}
+
+fn highlight_line(line: &str, language: Language, carry: Carry, runs: &mut Runs) -> Carry {
+    let chars: Vec<char> = line.chars().collect();
+    let mut at = 0;
+
+    // Finish whatever the previous line left open.
+    match carry {
+        Carry::Nothing => {}
+        Carry::BlockComment(depth) => match block_comment_end(&chars, 0, depth, language) {
+            Ok(end) => {
+                runs.push(Token::Comment, &chars[..end]);
+                at = end;
+            }
+            Err(depth) => {
+                runs.push(Token::Comment, &chars);
+                return Carry::BlockComment(depth);
+            }
+        },
+        Carry::RawString(hashes) => match raw_string_end(&chars, 0, hashes) {
+            Some(end) => {
+                runs.push(Token::String, &chars[..end]);
+                at = end;
+            }
+            None => {
+                runs.push(Token::String, &chars);
+                return carry;
+            }
+        },
+        Carry::TripleString(quote) => {
+            let close: String = [quote; 3].iter().collect();
+            match find(&chars, 0, &close) {
+                Some(end) => {
+                    runs.push(Token::String, &chars[..end]);
+                    at = end;
+                }
+                None => {
+                    runs.push(Token::String, &chars);
+                    return carry;
+                }
+            }
+        }
+    }
+
+    if language == Language::Plain {
+        runs.push(Token::Plain, &chars[at..]);
+        return Carry::Nothing;
+    }
+
+    // Preprocessor directives and C# regions take the whole line.
+    let trimmed = line.trim_start();
+    if matches!(language, Language::Cpp | Language::CSharp) && trimmed.starts_with('#') {
+        runs.push(Token::Attribute, &chars[at..]);
+        return Carry::Nothing;
+    }
+
+    while at < chars.len() {
+        let c = chars[at];
+
+        if language
+            .line_comment()
+            .is_some_and(|marker| starts_with(&chars, at, marker))
+        {
+            runs.push(Token::Comment, &chars[at..]);
+            return Carry::Nothing;
+        }
+
+        if language.has_block_comments() && starts_with(&chars, at, "/*") {
+            match block_comment_end(&chars, at + 2, 1, language) {
+                Ok(end) => {
+                    runs.push(Token::Comment, &chars[at..end]);
+                    at = end;
+                    continue;
+                }
+                Err(depth) => {
+                    runs.push(Token::Comment, &chars[at..]);
+                    return Carry::BlockComment(depth);
+                }
+            }
+        }
+
+        // Rust raw strings: `r"…"`, `r#"…"#` and byte variants, where `\` escapes nothing.
+        if language == Language::Rust && (c == 'r' || starts_with(&chars, at, "br")) {
+            let open = at + if c == 'r' { 1 } else { 2 };
+            let hashes = chars[open..].iter().take_while(|&&c| c == '#').count();
+            let word_before = at > 0 && (chars[at - 1].is_alphanumeric() || chars[at - 1] == '_');
+            if !word_before && chars.get(open + hashes) == Some(&'"') {
+                match raw_string_end(&chars, open + hashes + 1, hashes) {
+                    Some(end) => {
+                        runs.push(Token::String, &chars[at..end]);
+                        at = end;
+                        continue;
+                    }
+                    None => {
+                        runs.push(Token::String, &chars[at..]);
+                        return Carry::RawString(hashes);
+                    }
+                }
+            }
+        }
+
+        if language == Language::Python && (c == '"' || c == '\'') {
+            let open: String = [c; 3].iter().collect();
+            if starts_with(&chars, at, &open) {
+                match find(&chars, at + 3, &open) {
+                    Some(end) => {
+                        runs.push(Token::String, &chars[at..end]);
+                        at = end;
+                        continue;
+                    }
+                    None => {
+                        runs.push(Token::String, &chars[at..]);
+                        return Carry::TripleString(c);
+                    }
+                }
+            }
+        }
+
+        // Rust lifetimes look like an unclosed character literal.
+        if language == Language::Rust
+            && c == '\''
+            && chars.get(at + 1).is_some_and(|next| next.is_alphabetic())
+            && chars.get(at + 2) != Some(&'\'')
+        {
+            let end = word_end(&chars, at + 1);
+            runs.push(Token::Attribute, &chars[at..end]);
+            at = end;
+            continue;
+        }
+
+        if c == '"' || c == '\'' || (c == '`' && language != Language::Rust) {
+            let end = string_end(&chars, at);
+            runs.push(Token::String, &chars[at..end]);
+            at = end;
+            continue;
+        }
+
+        if c.is_ascii_digit() {
+            let mut end = at + 1;
+            while end < chars.len()
+                && (chars[end].is_ascii_alphanumeric()
+                    || chars[end] == '_'
+                    || (chars[end] == '.' && chars.get(end + 1) != Some(&'.')))
+            {
+                end += 1;
+            }
+            runs.push(Token::Number, &chars[at..end]);
+            at = end;
+            continue;
+        }
+
+        if c.is_alphabetic() || c == '_' {
+            let end = word_end(&chars, at);
+            let word: String = chars[at..end].iter().collect();
+            let next = chars[end..].iter().find(|c| !c.is_whitespace()).copied();
+            let token = if language.keywords().contains(&word.as_str()) {
+                Token::Keyword
+            } else if language == Language::Rust && next == Some('!') {
+                Token::Attribute
+            } else if language.types().contains(&word.as_str())
+                || word.starts_with(|c: char| c.is_uppercase())
+            {
+                Token::Type
+            } else if next == Some('(') {
+                Token::Function
+            } else {
+                Token::Plain
+            };
+            runs.push(token, &chars[at..end]);
+            at = end;
+            continue;
+        }
+
+        // Rust attributes run to the closing bracket, Python decorators to the end of the name.
+        if (language == Language::Rust && c == '#') || (language == Language::Python && c == '@') {
+            let end = if chars.get(at + 1) == Some(&'[') || starts_with(&chars, at + 1, "![") {
+                find(&chars, at, "]").unwrap_or(chars.len())
+            } else {
+                word_end(&chars, at + 1)
+            };
+            runs.push(Token::Attribute, &chars[at..end]);
+            at = end;
+            continue;
+        }
+
+        let token = if c.is_whitespace() {
+            Token::Plain
+        } else {
+            Token::Punctuation
+        };
+        runs.push(token, &chars[at..at + 1]);
+        at += 1;
+    }
+    Carry::Nothing
+}
+
+/// Index just past the `*/` that closes block comments open `depth` deep, or the depth still
+/// open at the end of the line.
+fn block_comment_end(
+    chars: &[char],
+    from: usize,
+    mut depth: usize,
+    language: Language,
+) -> Result<usize, usize> {
+    let mut index = from;
+    while index < chars.len() {
+        if language == Language::Rust && starts_with(chars, index, "/*") {
+            depth += 1;
+            index += 2;
+        } else if starts_with(chars, index, "*/") {
+            depth -= 1;
+            index += 2;
+            if depth == 0 {
+                return Ok(index);
+            }
+        } else {
+            index += 1;
+        }
+    }
+    Err(depth)
+}
+
+/// Index just past the `"` and `hashes` `#`s that close a raw string, searching from `from`.
+fn raw_string_end(chars: &[char], from: usize, hashes: usize) -> Option<usize> {
+    (from..chars.len())
+        .find(|&index| {
+            chars[index] == '"'
+                && chars[index + 1..].iter().take_while(|&&c| c == '#').count() >= hashes
+        })
+        .map(|index| index + 1 + hashes)
+}
+
+/// End of the identifier starting at `from`.
+fn word_end(chars: &[char], from: usize) -> usize {
+    let mut end = from;
+    while end < chars.len() && (chars[end].is_alphanumeric() || chars[end] == '_') {
+        end += 1;
+    }
+    end
+}
+
+/// End of the string literal opened at `from`, or the end of the line if it is not closed.
+fn string_end(chars: &[char], from: usize) -> usize {
+    let quote = chars[from];
+    let mut index = from + 1;
+    while index < chars.len() {
+        match chars[index] {
+            '\\' => index += 2,
+            c if c == quote => return index + 1,
+            _ => index += 1,
+        }
+    }
+    chars.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(token: Token, text: &str) -> (Token, String) {
+        (token, text.to_string())
+    }
+
+    /// The text of every run with `token`, across all lines.
+    fn texts(source: &str, language: Language, token: Token) -> Vec<String> {
+        highlight(source, language)
+            .into_iter()
+            .flatten()
+            .filter(|(found, _)| *found == token)
+            .map(|(_, text)| text)
+            .collect()
+    }
+
+    #[test]
+    fn escaped_quotes_stay_inside_strings() {
+        assert_eq!(
+            texts(r#"let s = "a \" b"; x"#, Language::Rust, Token::String),
+            [r#""a \" b""#]
+        );
+        assert_eq!(
+            texts(r"c = '\\'; d = 'e'", Language::Python, Token::String),
+            [r"'\\'", "'e'"]
+        );
+        assert_eq!(
+            texts("s = `a ${b}` + 'c'", Language::JavaScript, Token::String),
+            ["`a ${b}`", "'c'"]
+        );
+    }
+
+    #[test]
+    fn unclosed_strings_end_with_the_line() {
+        let lines = highlight("x = \"open\nnext", Language::Go);
+        assert_eq!(lines[0].last(), Some(&run(Token::String, "\"open")));
+        assert_eq!(lines[1], [run(Token::Plain, "next")]);
+    }
+
+    #[test]
+    fn block_comments_carry_over_lines() {
+        let lines = highlight("int a; /* start\nstill */ int b;", Language::Cpp);
+        assert_eq!(lines[0].last(), Some(&run(Token::Comment, "/* start")));
+        assert_eq!(lines[1][0], run(Token::Comment, "still */"));
+        assert_eq!(lines[1][2], run(Token::Type, "int"));
+    }
+
+    #[test]
+    fn rust_block_comments_nest() {
+        let lines = highlight("/* a /* b */ c */ x\n/* /*\n*/ y */ z", Language::Rust);
+        assert_eq!(
+            lines[0],
+            [
+                run(Token::Comment, "/* a /* b */ c */"),
+                run(Token::Plain, " x")
+            ]
+        );
+        assert_eq!(lines[1], [run(Token::Comment, "/* /*")]);
+        assert_eq!(
+            lines[2],
+            [run(Token::Comment, "*/ y */"), run(Token::Plain, " z")]
+        );
+    }
+
+    #[test]
+    fn other_block_comments_close_at_the_first_end() {
+        assert_eq!(
+            texts("/* a /* b */ c", Language::CSharp, Token::Comment),
+            ["/* a /* b */"]
+        );
+    }
+
+    #[test]
+    fn rust_raw_strings_ignore_escapes() {
+        assert_eq!(
+            texts(r##"let p = r"C:\dir\"; q"##, Language::Rust, Token::String),
+            [r#"r"C:\dir\""#]
+        );
+        assert_eq!(
+            texts(
+                r###"f(r#"say "hi""#, br"\x")"###,
+                Language::Rust,
+                Token::String
+            ),
+            [r##"r#"say "hi""#"##, r#"br"\x""#]
+        );
+        // An identifier ending in `r` does not start one.
+        assert_eq!(
+            texts(r#"bar"x""#, Language::Rust, Token::String),
+            [r#""x""#]
+        );
+    }
+
+    #[test]
+    fn rust_raw_strings_carry_over_lines() {
+        let lines = highlight("let s = r#\"first\nsecond \"\"# ok", Language::Rust);
+        assert_eq!(lines[0].last(), Some(&run(Token::String, "r#\"first")));
+        assert_eq!(lines[1][0], run(Token::String, "second \"\"#"));
+        assert_eq!(lines[1][1], run(Token::Plain, " ok"));
+    }
+
+    #[test]
+    fn python_triple_quotes_carry_over_lines() {
+        let lines = highlight("doc = \"\"\"one\ntwo \"\"\" + x", Language::Python);
+        assert_eq!(lines[0].last(), Some(&run(Token::String, "\"\"\"one")));
+        assert_eq!(lines[1][0], run(Token::String, "two \"\"\""));
+    }
+
+    #[test]
+    fn rust_lifetimes_are_not_char_literals() {
+        assert_eq!(
+            texts("fn f<'a>(c: char) { 'x' }", Language::Rust, Token::String),
+            ["'x'"]
+        );
+        assert_eq!(
+            texts(
+                "fn f<'a>(c: char) { 'x' }",
+                Language::Rust,
+                Token::Attribute
+            ),
+            ["'a"]
+        );
+    }
+
+    #[test]
+    fn unknown_languages_pass_through() {
+        let language = Language::from_path("notes.xyz");
+        assert_eq!(language, Language::Plain);
+        let source = "fn main() { \"/* not code */\" }\n\n# still plain";
+        assert_eq!(
+            highlight(source, language),
+            [
+                vec![run(Token::Plain, "fn main() { \"/* not code */\" }")],
+                vec![],
+                vec![run(Token::Plain, "# still plain")],
+            ]
+        );
+    }
+
+    #[test]
+    fn languages_come_from_extensions_and_fence_labels() {
+        assert_eq!(Language::from_path("src/main.rs"), Language::Rust);
+        assert_eq!(Language::from_path("Gloves.CS"), Language::CSharp);
+        assert_eq!(Language::from_path("Makefile"), Language::Plain);
+        assert_eq!(Language::from_name(" c++ "), Language::Cpp);
+    }
+}
@@ -0,0 +1,58 @@
+//! Host resource readings from `/proc`, shared by the Admin dashboard and the Telemetry screen.
+//!
+//! Linux only; every reading is `None` elsewhere.
+
+use std::fs;
+
+/// Physical memory, in KiB.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Memory {
+    pub total_kb: u64,
+    pub available_kb: u64,
+}
+
+impl Memory {
+    pub fn used_kb(&self) -> u64 {
+        self.total_kb.saturating_sub(self.available_kb)
+    }
+
+    /// Share of memory in use, 0–100.
+    pub fn used_percent(&self) -> Option<f64> {
+        (self.total_kb > 0).then(|| self.used_kb() as f64 / self.total_kb as f64 * 100.0)
+    }
+}
+
+/// One-, five- and fifteen-minute load averages, from `/proc/loadavg`.
+pub fn load_average() -> Option<[f64; 3]> {
+    let text = fs::read_to_string("/proc/loadavg").ok()?;
+    let mut fields = text.split_whitespace().map(|field| field.parse().ok());
+    Some([fields.next()??, fields.next()??, fields.next()??])
+}
+
+/// Total and available memory, from `/proc/meminfo`.
+pub fn memory() -> Option<Memory> {
+    let text = fs::read_to_string("/proc/meminfo").ok()?;
+    Some(Memory {
+        total_kb: proc_kb(&text, "MemTotal:")?,
+        available_kb: proc_kb(&text, "MemAvailable:")?,
+    })
+}
+
+/// Resident set size of this process, from `/proc/self/status`.
+pub fn process_rss_kb() -> Option<u64> {
+    proc_kb(&fs::read_to_string("/proc/self/status").ok()?, "VmRSS:")
+}
+
+/// Seconds since the host booted, from `/proc/uptime`.
+pub fn uptime_secs() -> Option<u64> {
+    let text = fs::read_to_string("/proc/uptime").ok()?;
+    let secs: f64 = text.split_whitespace().next()?.parse().ok()?;
+    Some(secs as u64)
+}
+
+/// The number after `key` in a `/proc` file of `Key:   value kB` lines.
+fn proc_kb(text: &str, key: &str) -> Option<u64> {
+    text.lines()
+        .find_map(|line| line.strip_prefix(key))
+        .and_then(|rest| rest.split_whitespace().next()?.parse().ok())
+}
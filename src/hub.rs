@@ -0,0 +1,45 @@
+//! Process-wide notifications fanned out to every running session.
+
+use tokio::sync::broadcast;
+
+/// A message delivered to every session subscribed to the hub.
+#[derive(Clone, Debug)]
+pub enum Broadcast {
+    /// The process is being torn down; sessions should restore the terminal and exit.
+    Shutdown,
+}
+
+/// Create the hub sender. Sessions call `subscribe()` on it to receive broadcasts.
+pub fn channel() -> broadcast::Sender<Broadcast> {
+    broadcast::channel(16).0
+}
+
+/// Forward SIGTERM and SIGHUP (sent by sshd when the client disconnects) to the hub.
+#[cfg(unix)]
+pub fn spawn_signal_listener(tx: broadcast::Sender<Broadcast>) {
+    use tokio::signal::unix::{SignalKind, signal};
+
+    tokio::spawn(async move {
+        let (Ok(mut term), Ok(mut hup)) = (
+            signal(SignalKind::terminate()),
+            signal(SignalKind::hangup()),
+        ) else {
+            return;
+        };
+        tokio::select! {
+            _ = term.recv() => {}
+            _ = hup.recv() => {}
+        }
+        let _ = tx.send(Broadcast::Shutdown);
+    });
+}
+
+/// Forward Ctrl-Break / console close to the hub on platforms without unix signals.
+#[cfg(not(unix))]
+pub fn spawn_signal_listener(tx: broadcast::Sender<Broadcast>) {
+    tokio::spawn(async move {
+        if tokio::signal::ctrl_c().await.is_ok() {
+            let _ = tx.send(Broadcast::Shutdown);
+        }
+    });
+}
@@ -0,0 +1,61 @@
+//! Key handling shared by the screens that have bindings of their own.
+
+use std::cell::Cell;
+
+use crossterm::event::{KeyCode, KeyEvent};
+
+/// Lines moved by PageUp/PageDown in a [`Scroll`].
+const PAGE: usize = 10;
+
+/// Screen state that sees keys before the global bindings do.
+pub trait KeyHandler {
+    /// What the handler reads besides its own state, `()` for most screens.
+    type Context: ?Sized;
+
+    /// Handle `key`, returning the binding name for analytics, or `None` to let the global
+    /// bindings have it.
+    fn on_key(&mut self, key: KeyEvent, context: &Self::Context) -> Option<&'static str>;
+}
+
+/// Vertical position in a reader whose height only the renderer knows.
+#[derive(Debug, Default)]
+pub struct Scroll {
+    /// First line shown.
+    pub offset: usize,
+    /// Largest useful offset at the last rendered size, written by [`Scroll::fit`] so key
+    /// handling can clamp without knowing the layout.
+    max: Cell<usize>,
+}
+
+impl Scroll {
+    /// Record that `lines` of content show through `visible` rows, returning the offset to
+    /// draw from.
+    pub fn fit(&self, lines: usize, visible: usize) -> usize {
+        let max = lines.saturating_sub(visible);
+        self.max.set(max);
+        self.offset.min(max)
+    }
+
+    /// Largest useful offset as of the last [`Scroll::fit`].
+    pub fn max(&self) -> usize {
+        self.max.get()
+    }
+}
+
+impl KeyHandler for Scroll {
+    type Context = ();
+
+    fn on_key(&mut self, key: KeyEvent, _: &()) -> Option<&'static str> {
+        let max = self.max.get();
+        self.offset = match key.code {
+            KeyCode::Down | KeyCode::Char('j') => (self.offset + 1).min(max),
+            KeyCode::Up | KeyCode::Char('k') => self.offset.saturating_sub(1),
+            KeyCode::PageDown | KeyCode::Char(' ') => (self.offset + PAGE).min(max),
+            KeyCode::PageUp => self.offset.saturating_sub(PAGE),
+            KeyCode::Home | KeyCode::Char('g') => 0,
+            KeyCode::End | KeyCode::Char('G') => max,
+            _ => return None,
+        };
+        Some("scroll")
+    }
+}
@@ -0,0 +1,97 @@
+//! Series selection and the full chart view on the Telemetry screen.
+
+use crossterm::event::{KeyCode, KeyEvent};
+
+use crate::{input::KeyHandler, telemetry::History};
+
+/// Readings a shifted scrub key jumps at once.
+const SCRUB_STEP: isize = 10;
+
+#[derive(Debug, Default)]
+pub struct Inspector {
+    /// Series highlighted in the grid and shown in the chart.
+    pub selected: usize,
+    /// `true` while the chart replaces the grid.
+    pub open: bool,
+    /// The histories as they were when the chart was paused; sampling carries on underneath.
+    pub frozen: Option<History>,
+    /// Sample under the scrub cursor, `None` while following the latest one.
+    pub cursor: Option<usize>,
+}
+
+impl Inspector {
+    /// What the screen shows: the frozen histories while paused, otherwise `live`.
+    pub fn history<'a>(&'a self, live: &'a History) -> &'a History {
+        self.frozen.as_ref().unwrap_or(live)
+    }
+
+    /// Move the cursor by `delta` samples, pausing first so the history holds still.
+    fn scrub(&mut self, live: &History, delta: isize) -> Option<&'static str> {
+        let frozen = self.frozen.get_or_insert_with(|| live.clone());
+        let last = frozen.times.len().saturating_sub(1);
+        let at = self.cursor.unwrap_or(last);
+        self.cursor = Some(at.saturating_add_signed(delta).min(last));
+        Some("telemetry-scrub")
+    }
+
+    fn resume(&mut self) {
+        self.frozen = None;
+        self.cursor = None;
+    }
+}
+
+impl KeyHandler for Inspector {
+    type Context = History;
+
+    fn on_key(&mut self, key: KeyEvent, live: &History) -> Option<&'static str> {
+        let count = live.series.len().max(1);
+        if !self.open {
+            return match key.code {
+                KeyCode::Char('l') | KeyCode::Right | KeyCode::Tab => {
+                    self.selected = (self.selected + 1) % count;
+                    Some("telemetry-select")
+                }
+                KeyCode::Char('h') | KeyCode::Left | KeyCode::BackTab => {
+                    self.selected = (self.selected + count - 1) % count;
+                    Some("telemetry-select")
+                }
+                KeyCode::Enter => {
+                    self.selected = self.selected.min(count - 1);
+                    self.open = true;
+                    Some("telemetry-chart")
+                }
+                _ => None,
+            };
+        }
+        match key.code {
+            KeyCode::Esc | KeyCode::Backspace => {
+                self.open = false;
+                self.resume();
+                Some("telemetry-close")
+            }
+            KeyCode::Char(' ') => {
+                if self.frozen.is_some() {
+                    self.resume();
+                } else {
+                    self.frozen = Some(live.clone());
+                }
+                Some("telemetry-pause")
+            }
+            KeyCode::Tab => {
+                self.selected = (self.selected + 1) % count;
+                Some("telemetry-select")
+            }
+            KeyCode::BackTab => {
+                self.selected = (self.selected + count - 1) % count;
+                Some("telemetry-select")
+            }
+            KeyCode::Char('h') | KeyCode::Left => self.scrub(live, -1),
+            KeyCode::Char('l') | KeyCode::Right => self.scrub(live, 1),
+            KeyCode::Char('H') => self.scrub(live, -SCRUB_STEP),
+            KeyCode::Char('L') => self.scrub(live, SCRUB_STEP),
+            KeyCode::Char('g') | KeyCode::Home => self.scrub(live, isize::MIN),
+            KeyCode::Char('G') | KeyCode::End => self.scrub(live, isize::MAX),
+            _ => None,
+        }
+    }
+}
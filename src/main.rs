@@ -1,43 +1,176 @@
+use std::time::{Duration, Instant, SystemTime};
+
 use color_eyre::Result;
-use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
-use rand::distr::{Distribution, Uniform};
+use crossterm::event::{Event, EventStream, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use futures::StreamExt;
 use ratatui::{DefaultTerminal, Frame, prelude::*};
+use tokio::{sync::broadcast, time::MissedTickBehavior};
+mod activity;
+mod admin;
+mod admission;
+mod analytics;
+mod arcade;
+mod art;
+mod blog;
+mod calendar;
+mod config;
+mod content;
+mod debug;
+mod highlight;
+mod host;
+mod hub;
+mod input;
+mod inspector;
+mod metrics;
+mod presence;
+mod projects;
+mod ring;
 mod screens;
-use screens::intro_screen::ASCII_FRAMES;
+mod session_guard;
+mod session_timer;
+mod shell;
+mod skills;
+mod state_files;
+mod telemetry;
+mod timeline;
+use analytics::{Analytics, Event as AnalyticsEvent};
+use config::Config;
+use hub::Broadcast;
+use input::KeyHandler;
+use screens::{
+    session_notice::Notice,
+    transition::{Transition, Travel},
+};
+use session_guard::SessionGuard;
 
-enum ScreenWidget {
-    Intro(screens::intro_screen::IntroScreenWidget),
-    First(screens::first_screen::FirstScreenWidget),
-    Second(screens::second_screen::SecondScreenWidget),
-    Third(screens::third_screen::SparkWidget),
+enum ScreenWidget<'a> {
+    Intro(screens::intro_screen::IntroScreenWidget<'a>),
+    First(screens::first_screen::FirstScreenWidget<'a>),
+    Second(screens::second_screen::SecondScreenWidget<'a>),
+    Third(screens::third_screen::SparkWidget<'a>),
+    Arcade(screens::arcade_screen::ArcadeScreenWidget<'a>),
+    Blog(screens::blog_screen::BlogScreenWidget<'a>),
+    Activity(screens::activity_screen::ActivityScreenWidget<'a>),
+    Shell(screens::shell_screen::ShellScreenWidget<'a>),
+    Admin(screens::admin_screen::AdminScreenWidget<'a>),
 }
 
-impl ratatui::widgets::Widget for ScreenWidget {
+impl ratatui::widgets::Widget for ScreenWidget<'_> {
     fn render(self, area: Rect, buf: &mut Buffer) {
         match self {
             ScreenWidget::Intro(widget) => widget.render(area, buf),
             ScreenWidget::First(widget) => widget.render(area, buf),
             ScreenWidget::Second(widget) => widget.render(area, buf),
             ScreenWidget::Third(widget) => widget.render(area, buf),
+            ScreenWidget::Arcade(widget) => widget.render(area, buf),
+            ScreenWidget::Blog(widget) => widget.render(area, buf),
+            ScreenWidget::Activity(widget) => widget.render(area, buf),
+            ScreenWidget::Shell(widget) => widget.render(area, buf),
+            ScreenWidget::Admin(widget) => widget.render(area, buf),
         }
     }
 }
 
-fn main() -> color_eyre::Result<()> {
+#[tokio::main(flavor = "current_thread")]
+async fn main() -> color_eyre::Result<()> {
     color_eyre::install()?;
+    let hub = hub::channel();
+    hub::spawn_signal_listener(hub.clone());
+    let config = Config::from_env();
+    if std::env::args().nth(1).as_deref() == Some("refresh-activity") {
+        let activity = activity::refresh(&config)?;
+        println!(
+            "Cached {} contributions and {} repositories for {} in {}",
+            activity.total(),
+            activity.repos.len(),
+            activity.user,
+            config.activity_path().display()
+        );
+        return Ok(());
+    }
+    if std::env::args().nth(1).as_deref() == Some("serve-metrics") {
+        return metrics::serve(&config);
+    }
+    let source = admission::source_key(&config);
     let terminal = ratatui::init();
-    let result = App::new().run(terminal);
+    let result = match admission::check(&config, &source) {
+        Ok(admission) => {
+            let app = App::new(config, &source);
+            drop(admission);
+            app.run(terminal, hub.subscribe()).await
+        }
+        Err(rejection) => turn_away(terminal, &config, &source, rejection, hub.subscribe()).await,
+    };
     ratatui::restore();
     result
 }
 
-#[derive(Debug, Default, PartialEq)]
+/// Show the "server busy" screen until a key is pressed, the timeout passes or the hub shuts
+/// down, logging the rejection.
+async fn turn_away(
+    mut terminal: DefaultTerminal,
+    config: &Config,
+    source: &str,
+    rejection: admission::Rejection,
+    mut hub: broadcast::Receiver<Broadcast>,
+) -> Result<()> {
+    admission::log_rejection(rejection, source);
+    // Wipe anything the stderr fallback printed over the screen.
+    terminal.clear()?;
+    if let Some(path) = config.analytics_path() {
+        Analytics::open(&path, &analytics::new_session_id()).reject(rejection.name());
+    }
+    let mut events = EventStream::new();
+    let timeout = tokio::time::sleep(BUSY_SCREEN_TIMEOUT);
+    tokio::pin!(timeout);
+    loop {
+        terminal.draw(|frame| {
+            frame.render_widget(screens::busy_screen::busy_screen(rejection), frame.area())
+        })?;
+        tokio::select! {
+            event = events.next() => match event {
+                Some(Ok(Event::Key(key))) if key.kind == KeyEventKind::Press => return Ok(()),
+                Some(Ok(_)) => {}
+                Some(Err(err)) => return Err(err.into()),
+                None => return Ok(()),
+            },
+            _ = &mut timeout => return Ok(()),
+            _ = hub.recv() => return Ok(()),
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
 enum State {
     #[default]
     Intro,
     First,
     Second,
     Third,
+    Arcade,
+    Blog,
+    Activity,
+    /// Pseudo-shell easter egg, opened with `:` or a backtick
+    Shell,
+    /// Hidden operator dashboard, outside the `n`/`p` rotation.
+    Admin,
+}
+
+impl State {
+    /// Stable identifier used in analytics.
+    fn name(&self) -> &'static str {
+        match self {
+            State::Intro => "intro",
+            State::First => "skills",
+            State::Second => "experience",
+            State::Third => "telemetry",
+            State::Arcade => "arcade",
+            State::Blog => "blog",
+            State::Activity => "activity",
+            State::Shell => "shell",
+            State::Admin => "admin",
+        }
+    }
 }
 /// The main application which holds the state and logic of the application.
 #[derive(Debug, Default)]
@@ -46,47 +179,189 @@ pub struct App {
     running: bool,
     call_sign: String,
     screen: State,
-    /// Data for third screen sparklines
-    spark_data: [Vec<u64>; 3],
-    /// Current frame index for intro ASCII animation
-    intro_frame_index: usize,
-    /// Tick accumulator to pace intro animation
-    intro_tick: u8,
+    /// Telemetry histories and alerts, series in the order of `config.telemetry`
+    feed: telemetry::Feed,
+    /// Produces telemetry samples; moved into the sampler task when the session starts
+    sampler: telemetry::Sampler,
+    /// Selected series, chart view and pause state on the Telemetry screen
+    inspector: inspector::Inspector,
+    /// Intro ASCII art pieces
+    art: Vec<art::ArtPiece>,
+    /// Which art piece and frame the intro is showing
+    art_playback: art::Playback,
     /// Global monotonic tick counter
     global_tick: u64,
     /// Tick value when the current screen was entered
     screen_entered_tick: u64,
+    config: Config,
+    /// Last frame drawn, kept as the outgoing image for the next transition
+    last_frame: Option<Buffer>,
+    /// Screen change currently being animated
+    transition: Option<Transition>,
+    /// Anonymized event log for this session
+    analytics: SessionGuard<Analytics>,
+    /// Health counters read by `serve-metrics`, if metrics are enabled
+    metrics: SessionGuard<metrics::Recorder>,
+    /// Frame timings for the F12 debug overlay, and whether it is showing
+    debug: debug::FrameStats,
+    /// This session's entry in the shared registry of live sessions
+    presence: Option<presence::Presence>,
+    /// Latest visitor counts from the registry
+    visitors: Option<presence::Snapshot>,
+    /// When the current screen was entered, for dwell times
+    screen_entered_at: Option<Instant>,
+    /// Why the session ended, recorded on disconnect
+    exit_reason: &'static str,
+    /// Whether this connection may open the Admin screen
+    admin_unlocked: bool,
+    /// Usage statistics shown on the Admin screen
+    dashboard: admin::Dashboard,
+    /// Snake game and high scores for the Arcade screen
+    arcade: arcade::Arcade,
+    /// Post list and reader position for the Blog screen
+    blog: blog::Blog,
+    /// Skill category, ordering and chart style on the Skills screen
+    skills: skills::Skills,
+    /// Selected project and code viewer on the Experience screen
+    projects: projects::Projects,
+    /// Gantt view and zoom on the Experience screen
+    timeline: timeline::Timeline,
+    /// GitHub activity from the local cache, if it has been populated
+    activity: Option<activity::Activity>,
+    /// Pseudo-shell state, kept for the whole session
+    shell: shell::Shell,
+    /// Screen to go back to when leaving the Shell or Admin screen
+    hidden_return: State,
+    /// Idle timeout and maximum session length
+    timer: session_timer::SessionTimer,
+    /// The limit that ended the session and when the goodbye frame went up
+    goodbye: Option<(session_timer::Limit, Instant)>,
 }
 const PRIMARY_CALL_SIGN: &str = "Karneeshkar V";
+/// Animation cadence; every screen's reveal timing is expressed in these ticks.
+const TICK_RATE: Duration = Duration::from_millis(200);
+/// Redraw cadence while a transition is playing.
+const TRANSITION_FRAME_RATE: Duration = Duration::from_millis(33);
+/// How long a turned-away visitor sees the "server busy" screen.
+const BUSY_SCREEN_TIMEOUT: Duration = Duration::from_secs(10);
+/// How long the goodbye frame stays up before the session ends.
+const GOODBYE_HOLD: Duration = Duration::from_secs(2);
+/// How often the Admin dashboard is refreshed (25 ticks = 5s).
+const ADMIN_REFRESH_TICKS: u64 = 25;
+/// How often the session rewrites its metrics file (25 ticks = 5s).
+const METRICS_FLUSH_TICKS: u64 = 25;
+/// Sparkline history length per series.
+const SPARK_HISTORY: usize = 100;
 const SECONDARY_CALL_SIGN: &str = "Veera";
 impl App {
-    pub fn new() -> Self {
-        let mut app = Self::default();
+    pub fn new(config: Config, source: &str) -> Self {
+        let session = analytics::new_session_id();
+        let presence = presence::Presence::join(&config.presence_dir(), &session, source).ok();
+        let mut app = Self {
+            art: art::load(config.art_dir.as_deref()),
+            analytics: config
+                .analytics_path()
+                .map(|path| SessionGuard::new(Analytics::open(&path, &session)))
+                .unwrap_or_default(),
+            metrics: config
+                .metrics_dir()
+                .map(|dir| SessionGuard::new(metrics::Recorder::open(&dir, &session)))
+                .unwrap_or_default(),
+            debug: debug::FrameStats::new(),
+            visitors: presence
+                .as_ref()
+                .map(|_| presence::snapshot(&config.presence_dir())),
+            presence,
+            admin_unlocked: admin::is_authorized(&config),
+            timer: session_timer::SessionTimer::new(&config),
+            arcade: arcade::Arcade::new(config.highscores_path()),
+            blog: blog::Blog::new(blog::load(config.blog_dir.as_deref())),
+            projects: projects::Projects::new(projects::load_snippets(
+                config.snippets_dir.as_deref(),
+            )),
+            activity: activity::load(&config.activity_path()),
+            shell: shell::Shell::new(),
+            config,
+            ..Self::default()
+        };
         // Initialize sparkline data so third screen has something to show
-        let mut rng = rand::rng();
-        let dist = Uniform::new(0, 100).unwrap();
-        app.spark_data = [
-            (0..100).map(|_| dist.sample(&mut rng)).collect(),
-            (0..100).map(|_| dist.sample(&mut rng)).collect(),
-            (0..100).map(|_| dist.sample(&mut rng)).collect(),
-        ];
+        app.sampler = telemetry::Sampler::new(&app.config.telemetry);
+        let mut history = telemetry::History::new(app.config.telemetry.len(), SPARK_HISTORY);
+        let period = TICK_RATE.as_millis() as u64;
+        let start = analytics::unix_millis(SystemTime::now()) - period * SPARK_HISTORY as u64;
+        for step in 1..=SPARK_HISTORY as u64 {
+            history.push(app.sampler.sample(), start + period * step);
+        }
+        app.feed = telemetry::Feed::new(&app.config.telemetry, history);
         app.call_sign = PRIMARY_CALL_SIGN.to_string();
         app
     }
 
     /// Run the application's main loop.
-    pub fn run(mut self, mut terminal: DefaultTerminal) -> Result<()> {
+    ///
+    /// Terminal input, animation ticks, telemetry samples and hub broadcasts are all awaited
+    /// together, and the UI is redrawn after whichever arrives first.
+    pub async fn run(
+        mut self,
+        mut terminal: DefaultTerminal,
+        mut hub: broadcast::Receiver<Broadcast>,
+    ) -> Result<()> {
         self.running = true;
-        let tick_rate = std::time::Duration::from_millis(200);
+        let size = terminal.size()?;
+        self.analytics.record(AnalyticsEvent::Connect {
+            term: std::env::var("TERM").ok(),
+            width: size.width,
+            height: size.height,
+        });
+        self.record_screen_entered();
+        let mut events = EventStream::new();
+        let mut ticks = tokio::time::interval(TICK_RATE);
+        let mut samples = telemetry::spawn_sampler(TICK_RATE, std::mem::take(&mut self.sampler));
+        let mut visitors = match &self.presence {
+            Some(presence) => presence.spawn_heartbeat(),
+            // Without a registry entry the channel is closed and never yields.
+            None => tokio::sync::mpsc::channel(1).1,
+        };
+        let mut frames = tokio::time::interval(TRANSITION_FRAME_RATE);
+        frames.set_missed_tick_behavior(MissedTickBehavior::Skip);
+
+        self.draw(&mut terminal)?;
         while self.running {
-            if event::poll(tick_rate)? {
-                self.handle_crossterm_events()?;
-            } else {
-                // No input within tick_rate; advance app state
-                self.on_tick();
+            tokio::select! {
+                event = events.next() => match event {
+                    Some(Ok(event)) => self.handle_crossterm_event(event),
+                    Some(Err(err)) => {
+                        self.end_session("error");
+                        return Err(err.into());
+                    }
+                    None => self.quit("input_closed"),
+                },
+                _ = ticks.tick() => self.on_tick(),
+                Some(sample) = samples.recv() => self.on_sample(sample),
+                Some(snapshot) = visitors.recv() => self.visitors = Some(snapshot),
+                message = hub.recv() => match message {
+                    Ok(message) => self.on_broadcast(message),
+                    Err(broadcast::error::RecvError::Lagged(_)) => {}
+                    Err(broadcast::error::RecvError::Closed) => self.quit("hub_closed"),
+                },
+                _ = frames.tick(), if self.transition.is_some() => {}
+            }
+            if let Err(err) = self.draw(&mut terminal) {
+                self.end_session("error");
+                return Err(err);
             }
-            terminal.draw(|frame| self.render(frame))?;
         }
+        self.end_session(self.exit_reason);
+        Ok(())
+    }
+
+    /// Draw one frame, timing it for the metrics and the debug overlay.
+    fn draw(&mut self, terminal: &mut DefaultTerminal) -> Result<()> {
+        let started = Instant::now();
+        let completed = terminal.draw(|frame| self.render(frame))?;
+        let elapsed = started.elapsed();
+        self.metrics.observe_frame(elapsed);
+        self.debug.observe_frame(elapsed, completed.buffer);
         Ok(())
     }
 
@@ -105,97 +380,294 @@ impl App {
         let screen_tick = self.screen_tick();
         let global_tick = self.global_tick;
 
-        // 1-frame blank wipe on screen transition
-        if screen_tick == 0 {
-            let area = frame.area();
-            frame.buffer_mut().set_style(
-                area,
-                Style::new()
-                    .bg(screens::theme::BG_CANVAS)
-                    .fg(screens::theme::BG_CANVAS),
-            );
-            return;
-        }
-
         let widget = match self.screen {
-            State::Intro => ScreenWidget::Intro(screens::intro_screen::intro_screen(
-                ASCII_FRAMES[self.intro_frame_index],
-                page,
-                total,
-                screen_tick,
-                global_tick,
-            )),
+            State::Intro => {
+                let piece = &self.art[self.art_playback.piece];
+                ScreenWidget::Intro(screens::intro_screen::intro_screen(
+                    piece,
+                    &piece.frames[self.art_playback.frame],
+                    self.visitors,
+                    page,
+                    total,
+                    screen_tick,
+                    global_tick,
+                ))
+            }
             State::First => ScreenWidget::First(screens::first_screen::first_screen(
                 &self.call_sign,
+                &self.skills,
                 page,
                 total,
                 screen_tick,
             )),
             State::Second => ScreenWidget::Second(screens::second_screen::second_screen(
                 &self.call_sign,
+                &self.projects,
+                &self.timeline,
                 page,
                 total,
                 screen_tick,
                 global_tick,
             )),
             State::Third => ScreenWidget::Third(screens::third_screen::third_screen_from(
-                &self.spark_data,
+                &self.config.telemetry,
+                &self.feed,
+                &self.inspector,
                 page,
                 total,
                 screen_tick,
                 global_tick,
             )),
+            State::Arcade => ScreenWidget::Arcade(screens::arcade_screen::arcade_screen(
+                &self.arcade,
+                page,
+                total,
+                global_tick,
+            )),
+            State::Blog => {
+                ScreenWidget::Blog(screens::blog_screen::blog_screen(&self.blog, page, total))
+            }
+            State::Activity => ScreenWidget::Activity(screens::activity_screen::activity_screen(
+                self.activity.as_ref(),
+                page,
+                total,
+                screen_tick,
+            )),
+            State::Shell => ScreenWidget::Shell(screens::shell_screen::shell_screen(
+                &self.shell,
+                page,
+                total,
+                global_tick,
+            )),
+            State::Admin => ScreenWidget::Admin(screens::admin_screen::admin_screen(
+                &self.dashboard,
+                page,
+                total,
+                global_tick,
+            )),
         };
 
         frame.render_widget(widget, frame.area());
+
+        if let Some(transition) = &self.transition {
+            transition.compose(frame.buffer_mut());
+            if transition.finished() {
+                self.transition = None;
+            }
+        }
+        // The outgoing image for the next transition is the screen without the overlays.
+        if self.config.active_transition().is_some() {
+            self.last_frame = Some(frame.buffer_mut().clone());
+        }
+
+        let notice = match self.goodbye {
+            Some((limit, _)) => Some(Notice::Goodbye(limit)),
+            None => self
+                .timer
+                .warning()
+                .map(|(limit, left)| Notice::Warning(limit, left)),
+        };
+        if let Some(notice) = notice {
+            frame.render_widget(
+                screens::session_notice::session_notice(notice, global_tick),
+                frame.area(),
+            );
+        }
+        if self.debug.visible {
+            frame.render_widget(
+                screens::debug_overlay::debug_overlay(
+                    &self.debug,
+                    self.timer.since_input(),
+                    TICK_RATE,
+                    screen_tick,
+                    global_tick,
+                ),
+                frame.area(),
+            );
+        }
     }
 
-    /// Reads the crossterm events and updates the state of [`App`].
-    ///
-    /// If your application needs to perform work in between handling events, you can use the
-    /// [`event::poll`] function to check if there are any events available with a timeout.
-    fn handle_crossterm_events(&mut self) -> Result<()> {
-        match event::read()? {
+    /// Updates the state of [`App`] from a crossterm event read off the [`EventStream`].
+    fn handle_crossterm_event(&mut self, event: Event) {
+        match event {
             // it's important to check KeyEventKind::Press to avoid handling key release events
             Event::Key(key) if key.kind == KeyEventKind::Press => self.on_key_event(key),
             Event::Mouse(_) => {}
-            Event::Resize(_, _) => {}
+            Event::Resize(width, height) => {
+                self.analytics
+                    .record(AnalyticsEvent::Resize { width, height });
+            }
             _ => {}
         }
-        Ok(())
+    }
+
+    /// Reacts to a message fanned out through the hub.
+    fn on_broadcast(&mut self, message: Broadcast) {
+        match message {
+            Broadcast::Shutdown => self.quit("hangup"),
+        }
     }
 
     /// Handles the key events and updates the state of [`App`].
     fn on_key_event(&mut self, key: KeyEvent) {
-        match (key.modifiers, key.code) {
-            (_, KeyCode::Esc | KeyCode::Char('q'))
-            | (KeyModifiers::CONTROL, KeyCode::Char('c') | KeyCode::Char('C')) => self.quit(),
-            (_, KeyCode::Char('[')) => self.set_call_sign(PRIMARY_CALL_SIGN),
-            (_, KeyCode::Char(']')) => self.set_call_sign(SECONDARY_CALL_SIGN),
-            (_, KeyCode::Char('n')) => self.next_screen(),
-            (_, KeyCode::Char('p')) => self.previous_screen(),
-            _ => {}
+        let interrupt = key.modifiers == KeyModifiers::CONTROL
+            && matches!(key.code, KeyCode::Char('c') | KeyCode::Char('C'));
+        // Any key dismisses a session-limit warning without doing anything else.
+        if self.timer.on_input() && !interrupt {
+            return;
+        }
+        if key.code == KeyCode::F(12) {
+            self.debug.toggle();
+            self.analytics.record(AnalyticsEvent::Key {
+                key: "f12".to_string(),
+            });
+            return;
+        }
+        if self.screen == State::Shell && !interrupt {
+            let binding = match self.shell.on_key(key) {
+                shell::Reaction::Edit => return,
+                shell::Reaction::Ran(command) => format!("shell-{command}"),
+                shell::Reaction::Exit => {
+                    self.enter_screen(self.hidden_return, Travel::Backward);
+                    "shell-exit".to_string()
+                }
+            };
+            self.analytics.record(AnalyticsEvent::Key { key: binding });
+            return;
+        }
+        let screen_binding = match self.screen {
+            State::Arcade if !interrupt => self.arcade.on_key(key, &()),
+            State::First if !interrupt => self.skills.on_key(key, &()),
+            State::Second if !interrupt => match self.projects.on_key(key, &()) {
+                None if !self.projects.viewing => self.timeline.on_key(key, &()),
+                binding => binding,
+            },
+            State::Third if !interrupt => self.inspector.on_key(key, &self.feed.history),
+            State::Blog if !interrupt => self.blog.on_key(key, &()),
+            _ => None,
+        };
+        if let Some(link) = self.skills.follow.take() {
+            self.follow_skill_link(link);
+        }
+        if let Some(binding) = screen_binding {
+            self.analytics.record(AnalyticsEvent::Key {
+                key: binding.to_string(),
+            });
+            return;
         }
+        let binding = match (key.modifiers, key.code) {
+            (KeyModifiers::CONTROL, KeyCode::Char('c') | KeyCode::Char('C')) => {
+                self.quit("interrupt");
+                "ctrl-c"
+            }
+            (_, KeyCode::Esc) => {
+                self.quit("quit");
+                "esc"
+            }
+            (_, KeyCode::Char('q')) => {
+                self.quit("quit");
+                "q"
+            }
+            (_, KeyCode::Char('[')) => {
+                self.set_call_sign(PRIMARY_CALL_SIGN);
+                "["
+            }
+            (_, KeyCode::Char(']')) => {
+                self.set_call_sign(SECONDARY_CALL_SIGN);
+                "]"
+            }
+            (_, KeyCode::Char('n')) => {
+                self.next_screen();
+                "n"
+            }
+            (_, KeyCode::Char('p')) => {
+                self.previous_screen();
+                "p"
+            }
+            (_, KeyCode::Char(':') | KeyCode::Char('`')) => {
+                self.hidden_return = self.screen;
+                self.enter_screen(State::Shell, Travel::Forward);
+                "shell"
+            }
+            (_, KeyCode::Char('A')) if self.admin_unlocked => {
+                self.toggle_admin();
+                "A"
+            }
+            _ => return,
+        };
+        self.analytics.record(AnalyticsEvent::Key {
+            key: binding.to_string(),
+        });
+    }
+
+    /// Opens the Experience screen on an entry linked from the Skills screen.
+    fn follow_skill_link(&mut self, link: skills::Link) {
+        match link {
+            skills::Link::Experience(index) => self.projects.experience = Some(index),
+            skills::Link::Project(index) => {
+                self.projects.experience = None;
+                self.projects.selected = index;
+                self.projects.viewing = false;
+            }
+        }
+        self.skills.browsing = false;
+        self.enter_screen(State::Second, Travel::Forward);
     }
 
     fn next_screen(&mut self) {
-        self.screen = match self.screen {
+        let next = match self.screen {
             State::Intro => State::First,
             State::First => State::Second,
             State::Second => State::Third,
-            State::Third => State::First,
+            State::Third => State::Arcade,
+            State::Arcade => State::Blog,
+            State::Blog => State::Activity,
+            State::Activity => State::First,
+            State::Shell | State::Admin => State::Intro,
         };
-        self.screen_entered_tick = self.global_tick;
+        self.enter_screen(next, Travel::Forward);
     }
 
     fn previous_screen(&mut self) {
-        self.screen = match self.screen {
-            State::Intro => State::Third,
+        let previous = match self.screen {
+            State::Intro => State::Activity,
             State::First => State::Intro,
             State::Second => State::First,
             State::Third => State::Second,
+            State::Arcade => State::Third,
+            State::Blog => State::Arcade,
+            State::Activity => State::Blog,
+            State::Shell | State::Admin => State::Activity,
         };
+        self.enter_screen(previous, Travel::Backward);
+    }
+
+    /// Open the Admin screen, or return to where the operator came from.
+    fn toggle_admin(&mut self) {
+        if self.screen == State::Admin {
+            self.enter_screen(self.hidden_return, Travel::Backward);
+        } else {
+            self.hidden_return = self.screen;
+            self.dashboard.refresh(&self.config);
+            self.enter_screen(State::Admin, Travel::Forward);
+        }
+    }
+
+    /// Switch screens, restarting reveal animations and starting a transition if enabled.
+    fn enter_screen(&mut self, screen: State, travel: Travel) {
+        self.record_screen_left();
+        self.screen = screen;
         self.screen_entered_tick = self.global_tick;
+        self.record_screen_entered();
+        self.transition = match (self.config.active_transition(), self.last_frame.take()) {
+            (Some(kind), Some(from)) => Some(Transition::new(
+                kind,
+                travel,
+                from,
+                self.config.transition_duration,
+            )),
+            _ => None,
+        };
     }
     fn screen_index(&self) -> (usize, usize) {
         let page = match self.screen {
@@ -203,13 +675,44 @@ impl App {
             State::First => 2,
             State::Second => 3,
             State::Third => 4,
+            State::Arcade => 5,
+            State::Blog => 6,
+            State::Activity => 7,
+            // Hidden from the tab bar
+            State::Shell | State::Admin => 0,
         };
-        (page, 4)
+        (page, 7)
     }
 
-    /// Set running to false to quit the application.
-    fn quit(&mut self) {
+    /// Set running to false to quit the application, remembering why for analytics.
+    fn quit(&mut self, reason: &'static str) {
         self.running = false;
+        self.exit_reason = reason;
+    }
+
+    /// Close out the analytics for this session.
+    fn end_session(&mut self, reason: &str) {
+        self.record_screen_left();
+        self.analytics.finish(reason);
+        self.metrics.finish(reason);
+    }
+
+    fn record_screen_entered(&mut self) {
+        self.screen_entered_at = Some(Instant::now());
+        self.metrics.set_screen(self.screen.name());
+        self.analytics.record(AnalyticsEvent::ScreenEntered {
+            screen: self.screen.name().to_string(),
+        });
+    }
+
+    fn record_screen_left(&mut self) {
+        let Some(entered) = self.screen_entered_at.take() else {
+            return;
+        };
+        self.analytics.record(AnalyticsEvent::ScreenLeft {
+            screen: self.screen.name().to_string(),
+            dwell_ms: entered.elapsed().as_millis() as u64,
+        });
     }
 
     fn set_call_sign(&mut self, next: &str) {
@@ -221,41 +724,38 @@ impl App {
         self.global_tick.saturating_sub(self.screen_entered_tick)
     }
 
-    /// Periodic tick to update dynamic data (e.g., sparklines)
+    /// Periodic tick to advance animations
     fn on_tick(&mut self) {
         self.global_tick = self.global_tick.wrapping_add(1);
-        self.update_spark_data();
+        self.debug.observe_tick();
+        match self.goodbye {
+            Some((limit, shown)) if shown.elapsed() >= GOODBYE_HOLD => self.quit(limit.reason()),
+            Some(_) => {}
+            None => self.goodbye = self.timer.expired().map(|limit| (limit, Instant::now())),
+        }
         self.update_intro_animation();
+        if self.screen == State::Arcade {
+            self.arcade.tick();
+        }
+        if self.screen == State::Admin && self.screen_tick().is_multiple_of(ADMIN_REFRESH_TICKS) {
+            self.dashboard.refresh(&self.config);
+        }
+        if self.global_tick.is_multiple_of(METRICS_FLUSH_TICKS) {
+            self.metrics.flush();
+        }
     }
 
-    /// Append a new sample to each sparkline and keep a fixed history length
-    fn update_spark_data(&mut self) {
-        let mut rng = rand::rng();
-        let dist = Uniform::new(0, 100).unwrap();
-        for series in &mut self.spark_data {
-            series.push(dist.sample(&mut rng));
-            if series.len() > 100 {
-                series.remove(0);
-            }
-        }
+    /// Record a live telemetry sample and check it for alerts
+    fn on_sample(&mut self, sample: telemetry::Sample) {
+        let now = analytics::unix_millis(SystemTime::now());
+        self.feed.push(&self.config.telemetry, sample, now);
     }
 
     fn update_intro_animation(&mut self) {
-        let frame_count = ASCII_FRAMES.len();
-        if frame_count <= 1 {
-            return;
-        }
-
         if self.screen != State::Intro {
-            self.intro_tick = 0;
-            self.intro_frame_index %= frame_count;
+            self.art_playback.hold();
             return;
         }
-
-        self.intro_tick = self.intro_tick.saturating_add(1);
-        if self.intro_tick >= 4 {
-            self.intro_tick = 0;
-            self.intro_frame_index = (self.intro_frame_index + 1) % frame_count;
-        }
+        self.art_playback.advance(&self.art);
     }
 }
@@ -0,0 +1,389 @@
+//! Health metrics for operators, served in the Prometheus text format by `ssh-tui serve-metrics`.
+//!
+//! Like the presence registry this has to work across one process per connection. While
+//! `SSH_TUI_METRICS_ADDR` is set, each session keeps a `<session>.metrics` file in the metrics
+//! directory and rewrites it every few seconds with the screen it is on and its running counters.
+//! The server reads those files on every scrape; files of sessions that have ended, or that
+//! stopped being rewritten because their process died, are folded into the server's running
+//! totals and removed.
+
+use std::{
+    collections::BTreeMap,
+    fmt::Write as _,
+    fs,
+    io::{self, ErrorKind, Read, Write},
+    net::{TcpListener, TcpStream},
+    path::{Path, PathBuf},
+    sync::{
+        Mutex, PoisonError,
+        atomic::{AtomicUsize, Ordering},
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use color_eyre::{Result, eyre::eyre};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    config::Config,
+    presence,
+    session_guard::Finish,
+    state_files::{atomic_write, stale_entries},
+};
+
+/// Upper bounds of the frame render time buckets, in seconds.
+const FRAME_BUCKETS: [f64; 9] = [0.0005, 0.001, 0.0025, 0.005, 0.01, 0.025, 0.05, 0.1, 0.25];
+/// Files not rewritten for this long belong to sessions that died without finishing.
+const STALE_AFTER: Duration = Duration::from_secs(30);
+const METRICS_EXT: &str = "metrics";
+/// Longest a client may take to send its whole request.
+const REQUEST_DEADLINE: Duration = Duration::from_secs(5);
+/// Longest a client may take to accept any part of the response.
+const WRITE_TIMEOUT: Duration = Duration::from_secs(5);
+/// Requests with a longer head are refused.
+const MAX_REQUEST: usize = 16 * 1024;
+/// Connections handled at once; more are closed unanswered until one finishes.
+const MAX_IN_FLIGHT: usize = 8;
+/// Session end reasons counted as errors.
+const ERROR_REASONS: [&str; 2] = ["error", "panic"];
+
+/// Distribution of observations over [`FRAME_BUCKETS`].
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Histogram {
+    /// Observations per bucket, not cumulative; the last bucket has no upper bound.
+    buckets: Vec<u64>,
+    sum: f64,
+    count: u64,
+}
+
+impl Histogram {
+    fn observe(&mut self, value: f64) {
+        self.buckets.resize(FRAME_BUCKETS.len() + 1, 0);
+        let bucket = FRAME_BUCKETS
+            .iter()
+            .position(|&bound| value <= bound)
+            .unwrap_or(FRAME_BUCKETS.len());
+        self.buckets[bucket] += 1;
+        self.sum += value;
+        self.count += 1;
+    }
+
+    fn merge(&mut self, other: &Histogram) {
+        self.buckets.resize(FRAME_BUCKETS.len() + 1, 0);
+        for (bucket, count) in self.buckets.iter_mut().zip(&other.buckets) {
+            *bucket += count;
+        }
+        self.sum += other.sum;
+        self.count += other.count;
+    }
+}
+
+/// What one session reports about itself.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SessionMetrics {
+    /// Screen the session is on, as named in analytics.
+    pub screen: String,
+    pub frames: Histogram,
+    /// Bytes the session process has written to the terminal and to files alike.
+    pub process_bytes_written: u64,
+    /// Why the session ended, once it has.
+    pub ended: Option<String>,
+}
+
+/// Writer for one session's metrics file. A default-constructed recorder keeps nothing.
+#[derive(Debug, Default)]
+pub struct Recorder {
+    path: Option<PathBuf>,
+    metrics: SessionMetrics,
+}
+
+impl Recorder {
+    /// Start a metrics file for `session` in `dir`. Like analytics, failing to write it never
+    /// gets in the way of the session.
+    pub fn open(dir: &Path, session: &str) -> Self {
+        Self {
+            path: Some(dir.join(format!("{session}.{METRICS_EXT}"))),
+            metrics: SessionMetrics::default(),
+        }
+    }
+
+    pub fn set_screen(&mut self, screen: &str) {
+        self.metrics.screen = screen.to_string();
+    }
+
+    pub fn observe_frame(&mut self, elapsed: Duration) {
+        if self.path.is_some() {
+            self.metrics.frames.observe(elapsed.as_secs_f64());
+        }
+    }
+
+    /// Rewrite the file with the latest counters.
+    pub fn flush(&mut self) {
+        let Some(path) = &self.path else {
+            return;
+        };
+        if let Some(bytes) = process_bytes_written() {
+            self.metrics.process_bytes_written = bytes;
+        }
+        if let Ok(json) = serde_json::to_string(&self.metrics) {
+            let _ = atomic_write(path, json.as_bytes());
+        }
+    }
+}
+
+impl Finish for Recorder {
+    /// Write the end reason out with the final counters.
+    fn finish(&mut self, reason: &str) {
+        self.metrics.ended = Some(reason.to_string());
+        self.flush();
+    }
+}
+
+/// Bytes written by this process so far, terminal output and state files alike, from the
+/// `wchar` line of `/proc/self/io`.
+fn process_bytes_written() -> Option<u64> {
+    let text = fs::read_to_string("/proc/self/io").ok()?;
+    text.lines()
+        .find_map(|line| line.strip_prefix("wchar:"))?
+        .trim()
+        .parse()
+        .ok()
+}
+
+/// Counters of every session whose file the server has folded in.
+#[derive(Debug, Clone, Default)]
+struct Totals {
+    frames: Histogram,
+    process_bytes_written: u64,
+    errors: u64,
+}
+
+impl Totals {
+    fn add(&mut self, session: &SessionMetrics) {
+        self.frames.merge(&session.frames);
+        self.process_bytes_written += session.process_bytes_written;
+    }
+}
+
+/// Everything one scrape reports.
+#[derive(Debug, Default)]
+struct Scrape {
+    active: usize,
+    total: u64,
+    /// Live sessions per screen.
+    screens: BTreeMap<String, u64>,
+    counters: Totals,
+}
+
+/// Serve `/metrics` and `/healthz` on `SSH_TUI_METRICS_ADDR` until the process is stopped.
+///
+/// Each connection gets its own thread, up to [`MAX_IN_FLIGHT`], so a slow client only holds up
+/// itself; scrapes take turns folding session files into the shared totals.
+pub fn serve(config: &Config) -> Result<()> {
+    let addr = config
+        .metrics_addr
+        .as_deref()
+        .ok_or_else(|| eyre!("SSH_TUI_METRICS_ADDR must be set to serve metrics"))?;
+    let dir = config
+        .metrics_dir()
+        .ok_or_else(|| eyre!("no metrics directory configured"))?;
+    fs::create_dir_all(&dir)?;
+    let listener = TcpListener::bind(addr)?;
+    println!("Serving metrics on http://{addr}/metrics");
+    let totals = Mutex::new(Totals::default());
+    let in_flight = AtomicUsize::new(0);
+    thread::scope(|scope| {
+        for stream in listener.incoming().flatten() {
+            if in_flight.fetch_add(1, Ordering::AcqRel) >= MAX_IN_FLIGHT {
+                in_flight.fetch_sub(1, Ordering::AcqRel);
+                continue;
+            }
+            let (dir, totals, in_flight) = (&dir, &totals, &in_flight);
+            // A client that hangs up or stalls mid-request only loses its own response.
+            scope.spawn(move || {
+                let _ = respond(stream, config, dir, totals);
+                in_flight.fetch_sub(1, Ordering::AcqRel);
+            });
+        }
+    });
+    Ok(())
+}
+
+fn respond(
+    mut stream: TcpStream,
+    config: &Config,
+    dir: &Path,
+    totals: &Mutex<Totals>,
+) -> io::Result<()> {
+    stream.set_write_timeout(Some(WRITE_TIMEOUT))?;
+    // Only the request line matters; the headers are read just to reach the end of the request.
+    let head = read_head(&mut stream)?;
+    let mut parts = head.lines().next().unwrap_or_default().split_whitespace();
+    let method = parts.next().unwrap_or_default();
+    let path = parts.next().unwrap_or_default().split('?').next();
+    let plain = "text/plain; charset=utf-8";
+    let (status, content_type, body) = match (method, path) {
+        ("GET", Some("/metrics")) => (
+            "200 OK",
+            "text/plain; version=0.0.4; charset=utf-8",
+            render(&scrape(
+                config,
+                dir,
+                &mut totals.lock().unwrap_or_else(PoisonError::into_inner),
+            )),
+        ),
+        ("GET", Some("/healthz")) => match fs::read_dir(dir) {
+            Ok(_) => ("200 OK", plain, "ok\n".to_string()),
+            Err(err) => ("503 Service Unavailable", plain, format!("{err}\n")),
+        },
+        ("GET", _) => ("404 Not Found", plain, "not found\n".to_string()),
+        _ => (
+            "405 Method Not Allowed",
+            plain,
+            "method not allowed\n".to_string(),
+        ),
+    };
+    write!(
+        stream,
+        "HTTP/1.1 {status}\r\nContent-Type: {content_type}\r\nContent-Length: {}\r\n\
+         Connection: close\r\n\r\n{body}",
+        body.len()
+    )
+}
+
+/// Read the request line and headers, failing once they take longer than [`REQUEST_DEADLINE`]
+/// in total or run past [`MAX_REQUEST`] bytes.
+fn read_head(stream: &mut TcpStream) -> io::Result<String> {
+    let deadline = Instant::now() + REQUEST_DEADLINE;
+    let mut head = Vec::new();
+    let mut chunk = [0; 1024];
+    while !head.windows(2).any(|pair| pair == b"\n\n")
+        && !head.windows(3).any(|end| end == b"\n\r\n")
+    {
+        if head.len() > MAX_REQUEST {
+            return Err(ErrorKind::InvalidData.into());
+        }
+        let left = deadline.saturating_duration_since(Instant::now());
+        if left.is_zero() {
+            return Err(ErrorKind::TimedOut.into());
+        }
+        // Each read waits only as long as the whole request has left.
+        stream.set_read_timeout(Some(left))?;
+        let read = stream.read(&mut chunk)?;
+        if read == 0 {
+            break;
+        }
+        head.extend_from_slice(&chunk[..read]);
+    }
+    Ok(String::from_utf8_lossy(&head).into_owned())
+}
+
+/// Read the presence registry and every session's metrics file, folding finished and dead
+/// sessions into `totals`.
+fn scrape(config: &Config, dir: &Path, totals: &mut Totals) -> Scrape {
+    let visitors = presence::snapshot(&config.presence_dir());
+    let mut live = Vec::new();
+    for (path, stale) in stale_entries(dir, STALE_AFTER) {
+        if path.extension().is_none_or(|ext| ext != METRICS_EXT) {
+            continue;
+        }
+        let session: Option<SessionMetrics> = fs::read_to_string(&path)
+            .ok()
+            .and_then(|text| serde_json::from_str(&text).ok());
+        match session {
+            Some(session) if session.ended.is_none() && !stale => live.push(session),
+            Some(session) => {
+                totals.add(&session);
+                let failed = session
+                    .ended
+                    .as_deref()
+                    .is_none_or(|reason| ERROR_REASONS.contains(&reason));
+                totals.errors += u64::from(failed);
+                let _ = fs::remove_file(&path);
+            }
+            None if stale => {
+                let _ = fs::remove_file(&path);
+            }
+            None => {}
+        }
+    }
+
+    let mut scrape = Scrape {
+        active: visitors.online,
+        total: visitors.total_visits,
+        counters: totals.clone(),
+        ..Scrape::default()
+    };
+    for session in &live {
+        scrape.counters.add(session);
+        *scrape.screens.entry(session.screen.clone()).or_default() += 1;
+    }
+    scrape
+}
+
+/// Start a metric in `out` with its help and type lines, returning its name.
+fn describe<'a>(out: &mut String, name: &'a str, kind: &str, help: &str) -> &'a str {
+    let _ = writeln!(out, "# HELP {name} {help}\n# TYPE {name} {kind}");
+    name
+}
+
+/// The scrape in the Prometheus text exposition format.
+fn render(scrape: &Scrape) -> String {
+    let mut out = String::new();
+    let name = describe(
+        &mut out,
+        "ssh_tui_sessions_active",
+        "gauge",
+        "Sessions currently connected.",
+    );
+    let _ = writeln!(out, "{name} {}", scrape.active);
+    let name = describe(
+        &mut out,
+        "ssh_tui_sessions_total",
+        "counter",
+        "Sessions started since the state directory was created.",
+    );
+    let _ = writeln!(out, "{name} {}", scrape.total);
+    let name = describe(
+        &mut out,
+        "ssh_tui_sessions_by_screen",
+        "gauge",
+        "Connected sessions by the screen they are on.",
+    );
+    for (screen, sessions) in &scrape.screens {
+        let _ = writeln!(out, "{name}{{screen=\"{screen}\"}} {sessions}");
+    }
+
+    let frames = &scrape.counters.frames;
+    let name = describe(
+        &mut out,
+        "ssh_tui_frame_render_seconds",
+        "histogram",
+        "Time to render and write one frame.",
+    );
+    let mut cumulative = 0;
+    for (index, bound) in FRAME_BUCKETS.iter().enumerate() {
+        cumulative += frames.buckets.get(index).copied().unwrap_or(0);
+        let _ = writeln!(out, "{name}_bucket{{le=\"{bound}\"}} {cumulative}");
+    }
+    let _ = writeln!(out, "{name}_bucket{{le=\"+Inf\"}} {}", frames.count);
+    let _ = writeln!(out, "{name}_sum {}", frames.sum);
+    let _ = writeln!(out, "{name}_count {}", frames.count);
+
+    let name = describe(
+        &mut out,
+        "ssh_tui_process_bytes_written_total",
+        "counter",
+        "Bytes written by session processes, to terminals and state files alike.",
+    );
+    let _ = writeln!(out, "{name} {}", scrape.counters.process_bytes_written);
+    let name = describe(
+        &mut out,
+        "ssh_tui_errors_total",
+        "counter",
+        "Sessions that ended in an error or panic, or died without finishing.",
+    );
+    let _ = writeln!(out, "{name} {}", scrape.counters.errors);
+    out
+}
@@ -0,0 +1,123 @@
+//! Cross-process registry of live sessions, used for the "visitors online" count.
+//!
+//! sshd starts a separate process per connection, so sessions cannot share memory. Instead each
+//! one keeps a `<session>.session` file in the registry directory and refreshes its modification
+//! time on every heartbeat; files that stop being refreshed (crashed or killed sessions) are
+//! treated as gone and pruned by whoever notices first. Every join also appends one byte to a
+//! `visits` file, so the all-time visit count is simply that file's length. Entries hold the
+//! salted source key of the connection so per-source session caps can be enforced.
+
+use std::{
+    fs::{self, File, OpenOptions},
+    io::Write,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime},
+};
+
+use tokio::{sync::mpsc, time};
+
+use crate::state_files::stale_entries;
+
+/// How often a session refreshes its entry and re-counts the registry.
+const HEARTBEAT: Duration = Duration::from_secs(2);
+/// Entries not refreshed for this long belong to sessions that died without leaving.
+const STALE_AFTER: Duration = Duration::from_secs(10);
+const SESSION_EXT: &str = "session";
+
+/// Live numbers shown in the intro status bar.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Snapshot {
+    pub online: usize,
+    pub total_visits: u64,
+}
+
+/// This session's registration. Dropping it removes the entry.
+#[derive(Debug)]
+pub struct Presence {
+    dir: PathBuf,
+    entry: PathBuf,
+}
+
+impl Presence {
+    /// Register `session`, connected from `source`, in the registry at `dir`, counting it as a
+    /// new visit.
+    pub fn join(dir: &Path, session: &str, source: &str) -> std::io::Result<Self> {
+        fs::create_dir_all(dir)?;
+        let entry = dir.join(format!("{session}.{SESSION_EXT}"));
+        File::create(&entry)?.write_all(source.as_bytes())?;
+        OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(dir.join("visits"))?
+            .write_all(b"\n")?;
+        Ok(Self {
+            dir: dir.to_path_buf(),
+            entry,
+        })
+    }
+
+    /// Keep the entry fresh and report registry counts every [`HEARTBEAT`] until the receiver
+    /// is dropped.
+    pub fn spawn_heartbeat(&self) -> mpsc::Receiver<Snapshot> {
+        let (tx, rx) = mpsc::channel(1);
+        let dir = self.dir.clone();
+        let entry = self.entry.clone();
+        tokio::spawn(async move {
+            let mut ticker = time::interval(HEARTBEAT);
+            loop {
+                ticker.tick().await;
+                touch(&entry);
+                if tx.send(snapshot(&dir)).await.is_err() {
+                    break;
+                }
+            }
+        });
+        rx
+    }
+}
+
+impl Drop for Presence {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.entry);
+    }
+}
+
+/// Count live sessions in the registry at `dir`, pruning stale entries along the way.
+pub fn snapshot(dir: &Path) -> Snapshot {
+    let total_visits = fs::metadata(dir.join("visits")).map_or(0, |meta| meta.len());
+    Snapshot {
+        online: live_entries(dir).len(),
+        total_visits,
+    }
+}
+
+/// Number of live sessions connected from `source`.
+pub fn sessions_from(dir: &Path, source: &str) -> usize {
+    live_entries(dir)
+        .iter()
+        .filter(|path| fs::read_to_string(path).is_ok_and(|owner| owner == source))
+        .count()
+}
+
+/// Paths of the entries still being refreshed; stale ones are removed.
+fn live_entries(dir: &Path) -> Vec<PathBuf> {
+    let mut live = Vec::new();
+    for (path, stale) in stale_entries(dir, STALE_AFTER) {
+        if path.extension().is_none_or(|ext| ext != SESSION_EXT) {
+            continue;
+        }
+        if stale {
+            let _ = fs::remove_file(&path);
+        } else {
+            live.push(path);
+        }
+    }
+    live
+}
+
+/// Refresh the entry's modification time without recreating it after [`Presence`] is dropped.
+fn touch(entry: &Path) {
+    if let Ok(file) = OpenOptions::new().write(true).open(entry) {
+        let _ = file.set_modified(SystemTime::now());
+    }
+}
@@ -0,0 +1,139 @@
+//! Project selection, the code viewer and the highlighted role on the Experience screen.
+
+use std::{fs, path::Path};
+
+use crossterm::event::{KeyCode, KeyEvent};
+
+use crate::{
+    content::{PROJECTS, Project, recent_first},
+    input::{KeyHandler, Scroll},
+};
+
+/// A source file shown in the project code viewer.
+#[derive(Debug, Clone)]
+pub struct Snippet {
+    pub file: String,
+    pub source: String,
+}
+
+#[derive(Debug, Default)]
+pub struct Projects {
+    /// Index into [`PROJECTS`].
+    pub selected: usize,
+    /// `true` while the selected project's code is open.
+    pub viewing: bool,
+    /// Which of the project's snippets is shown.
+    pub file: usize,
+    /// Viewer position in the open snippet.
+    pub scroll: Scroll,
+    /// Index into [`EXPERIENCE`](crate::content::EXPERIENCE) to highlight, set when arriving
+    /// from a skill's links.
+    pub experience: Option<usize>,
+    /// Source files of each of [`PROJECTS`], in the same order.
+    snippets: Vec<Vec<Snippet>>,
+}
+
+impl Projects {
+    pub fn new(snippets: Vec<Vec<Snippet>>) -> Self {
+        Self {
+            snippets,
+            ..Self::default()
+        }
+    }
+
+    pub fn project(&self) -> &'static Project {
+        &PROJECTS[self.selected]
+    }
+
+    /// The selected project's source files.
+    pub fn snippets(&self) -> &[Snippet] {
+        self.snippets.get(self.selected).map_or(&[], Vec::as_slice)
+    }
+
+    pub fn snippet(&self) -> Option<&Snippet> {
+        self.snippets().get(self.file)
+    }
+}
+
+/// Source files for each of [`PROJECTS`], read from `<dir>/<slug>/` and ordered by file name.
+/// Nothing is built in, since only the owner's own code belongs in the viewer; files that are
+/// not UTF-8 text are skipped.
+pub fn load_snippets(dir: Option<&Path>) -> Vec<Vec<Snippet>> {
+    PROJECTS
+        .iter()
+        .map(|project| {
+            let Some(dir) = dir else {
+                return Vec::new();
+            };
+            let mut paths: Vec<_> = fs::read_dir(dir.join(project.slug))
+                .into_iter()
+                .flatten()
+                .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+                .filter(|path| path.is_file())
+                .collect();
+            paths.sort();
+            paths
+                .iter()
+                .filter_map(|path| {
+                    Some(Snippet {
+                        file: path.file_name()?.to_str()?.to_string(),
+                        source: fs::read_to_string(path).ok()?,
+                    })
+                })
+                .collect()
+        })
+        .collect()
+}
+
+impl KeyHandler for Projects {
+    type Context = ();
+
+    fn on_key(&mut self, key: KeyEvent, _: &()) -> Option<&'static str> {
+        if self.viewing {
+            if self.scroll.on_key(key, &()).is_some() {
+                return Some("code-scroll");
+            }
+            let files = self.snippets().len().max(1);
+            return match key.code {
+                KeyCode::Tab => {
+                    self.file = (self.file + 1) % files;
+                    self.scroll.offset = 0;
+                    Some("code-file")
+                }
+                KeyCode::BackTab => {
+                    self.file = (self.file + files - 1) % files;
+                    self.scroll.offset = 0;
+                    Some("code-file")
+                }
+                KeyCode::Esc | KeyCode::Backspace | KeyCode::Char('h') | KeyCode::Left => {
+                    self.viewing = false;
+                    Some("code-close")
+                }
+                _ => None,
+            };
+        }
+
+        // j/k walk the list in the order it is shown, newest first.
+        let order = recent_first(PROJECTS, |project| project.period);
+        let at = order
+            .iter()
+            .position(|&index| index == self.selected)
+            .unwrap_or(0);
+        match key.code {
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.selected = order[(at + 1) % order.len()];
+            }
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.selected = order[(at + order.len() - 1) % order.len()];
+            }
+            KeyCode::Enter | KeyCode::Char('l') | KeyCode::Right if !self.snippets().is_empty() => {
+                self.viewing = true;
+                self.file = 0;
+                self.scroll.offset = 0;
+                return Some("code-open");
+            }
+            _ => return None,
+        }
+        Some("project-select")
+    }
+}
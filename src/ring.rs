@@ -0,0 +1,112 @@
+//! Fixed-capacity ring buffer whose contents are always one contiguous slice.
+//!
+//! Every value is written twice, `capacity` slots apart, so the window starting at the oldest
+//! value never wraps. Pushing is O(1) and reading borrows in place; nothing is ever shifted.
+
+#[derive(Debug, Clone)]
+pub struct Ring<T> {
+    /// `2 * capacity` slots, the second half mirroring the first.
+    slots: Vec<T>,
+    capacity: usize,
+    /// Slot of the oldest value, always below `capacity`.
+    start: usize,
+    len: usize,
+}
+
+impl<T: Copy + Default> Ring<T> {
+    pub fn new(capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+        Self {
+            slots: vec![T::default(); capacity * 2],
+            capacity,
+            start: 0,
+            len: 0,
+        }
+    }
+
+    /// Append `value`, returning the oldest value if it had to make room.
+    pub fn push(&mut self, value: T) -> Option<T> {
+        let evicted = if self.len == self.capacity {
+            let oldest = self.slots[self.start];
+            self.start = (self.start + 1) % self.capacity;
+            self.len -= 1;
+            Some(oldest)
+        } else {
+            None
+        };
+        let slot = (self.start + self.len) % self.capacity;
+        self.slots[slot] = value;
+        self.slots[slot + self.capacity] = value;
+        self.len += 1;
+        evicted
+    }
+
+    /// Oldest first.
+    pub fn as_slice(&self) -> &[T] {
+        &self.slots[self.start..self.start + self.len]
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn first(&self) -> Option<T> {
+        self.as_slice().first().copied()
+    }
+
+    pub fn last(&self) -> Option<T> {
+        self.as_slice().last().copied()
+    }
+}
+
+impl<T: Copy + Default> Default for Ring<T> {
+    fn default() -> Self {
+        Self::new(1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fills_up_to_capacity_without_evicting() {
+        let mut ring = Ring::new(3);
+        assert_eq!(ring.first(), None);
+        assert_eq!(ring.push(1), None);
+        assert_eq!(ring.push(2), None);
+        assert_eq!(ring.push(3), None);
+        assert_eq!(ring.as_slice(), [1, 2, 3]);
+        assert_eq!((ring.first(), ring.last()), (Some(1), Some(3)));
+    }
+
+    #[test]
+    fn evicts_oldest_first_once_full() {
+        let mut ring = Ring::new(3);
+        let evicted: Vec<Option<u32>> = (1..=7).map(|value| ring.push(value)).collect();
+        assert_eq!(
+            evicted,
+            [None, None, None, Some(1), Some(2), Some(3), Some(4)]
+        );
+        assert_eq!(ring.len(), 3);
+    }
+
+    #[test]
+    fn slice_stays_in_order_through_wraparound() {
+        let mut ring = Ring::new(4);
+        for value in 0..23u32 {
+            ring.push(value);
+            let expected: Vec<u32> = (value.saturating_sub(3)..=value).collect();
+            assert_eq!(ring.as_slice(), expected);
+        }
+        assert_eq!((ring.first(), ring.last()), (Some(19), Some(22)));
+    }
+
+    #[test]
+    fn capacity_of_one_holds_the_latest() {
+        let mut ring = Ring::new(0);
+        assert_eq!(ring.push('a'), None);
+        assert_eq!(ring.push('b'), Some('a'));
+        assert_eq!(ring.as_slice(), ['b']);
+    }
+}
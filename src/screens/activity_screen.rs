@@ -0,0 +1,338 @@
+use std::collections::HashMap;
+
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Margin},
+    prelude::*,
+    style::Stylize,
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Cell, Padding, Paragraph, Row, Table},
+};
+
+use super::animation;
+use super::text_layout;
+use super::theme::*;
+use crate::{
+    activity::Activity,
+    calendar::{Date, unix_day},
+    content::GITHUB,
+};
+
+/// Most weeks the heatmap shows, a year plus the partial week at either end.
+const MAX_WEEKS: usize = 53;
+/// Languages listed by name; the rest are summed into "Other".
+const LISTED_LANGUAGES: usize = 5;
+const LANGUAGE_COLORS: [Color; LISTED_LANGUAGES] = [
+    ACCENT_TEAL,
+    ACCENT_BLUE,
+    ACCENT_VIOLET,
+    ACCENT_GOLD,
+    ACCENT_RED,
+];
+
+/// Contribution heatmap, top repositories and language mix from the activity cache.
+pub struct ActivityScreenWidget<'a> {
+    activity: Option<&'a Activity>,
+    page: usize,
+    total: usize,
+    screen_tick: u64,
+}
+
+pub fn activity_screen(
+    activity: Option<&Activity>,
+    page: usize,
+    total: usize,
+    screen_tick: u64,
+) -> ActivityScreenWidget<'_> {
+    ActivityScreenWidget {
+        activity,
+        page,
+        total,
+        screen_tick,
+    }
+}
+
+fn panel(title: String, accent: Color) -> Block<'static> {
+    Block::default()
+        .title(Span::styled(
+            format!("── {} ──", title),
+            Style::new().fg(accent).bold(),
+        ))
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::new().fg(BORDER_DIM))
+        .padding(Padding::horizontal(1))
+        .style(Style::new().bg(BG_PANEL))
+}
+
+/// Heatmap cell color for an intensity level, fading from the panel into the accent.
+fn shade(level: u8) -> Color {
+    let t = match level {
+        0 => return BORDER_DIM,
+        1 => 0.3,
+        2 => 0.55,
+        3 => 0.8,
+        _ => 1.0,
+    };
+    color_lerp(BG_PANEL, ACCENT_GREEN, t)
+}
+
+fn iso(date: Date) -> String {
+    format!("{}-{:02}-{:02}", date.year, date.month, date.day)
+}
+
+impl Widget for ActivityScreenWidget<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        buf.set_style(area, Style::new().bg(BG_CANVAS));
+
+        let content = area.inner(Margin {
+            horizontal: 2,
+            vertical: 1,
+        });
+
+        if content.width < 40 || content.height < 16 {
+            return;
+        }
+
+        let sections = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(11),
+                Constraint::Min(5),
+                Constraint::Length(5),
+            ])
+            .split(content);
+
+        let Some(activity) = self.activity else {
+            let message = Paragraph::new(vec![
+                Line::default(),
+                Line::from(Span::styled(
+                    "No GitHub activity cached yet.",
+                    Style::new().fg(FG_PRIMARY).bold(),
+                )),
+                Line::from(Span::styled(
+                    "Operators: run `ssh-tui refresh-activity` to fetch it.",
+                    Style::new().fg(FG_MUTED).italic(),
+                )),
+            ])
+            .alignment(Alignment::Center)
+            .block(panel("Contributions".to_string(), ACCENT_GREEN));
+            message.render(sections[0], buf);
+            render_footer(sections[2], buf, self.page, self.total, GITHUB);
+            return;
+        };
+
+        self.render_heatmap(activity, sections[0], buf);
+
+        let columns = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([
+                Constraint::Percentage(55),
+                Constraint::Length(1), // spacer
+                Constraint::Percentage(45),
+            ])
+            .split(sections[1]);
+        render_repos(activity, columns[0], buf);
+        render_languages(activity, columns[2], buf);
+
+        let updated = Date::from_unix_days(unix_day(activity.fetched_at));
+        render_footer(
+            sections[2],
+            buf,
+            self.page,
+            self.total,
+            &format!("{GITHUB} · cached {}", iso(updated)),
+        );
+    }
+}
+
+impl ActivityScreenWidget<'_> {
+    fn render_heatmap(&self, activity: &Activity, area: Rect, buf: &mut Buffer) {
+        let block = panel(format!("Contributions · @{}", activity.user), ACCENT_GREEN)
+            .title_bottom(
+                Line::from(vec![
+                    Span::styled(
+                        format!(" {} contributions", activity.total()),
+                        Style::new().fg(FG_PRIMARY).bold(),
+                    ),
+                    Span::styled(
+                        format!(
+                            " in the last year · longest streak {} days ",
+                            activity.longest_streak()
+                        ),
+                        Style::new().fg(FG_SECONDARY),
+                    ),
+                ])
+                .centered(),
+            );
+        let inner = block.inner(area);
+        block.render(area, buf);
+
+        let counts: HashMap<i64, u32> = activity
+            .days
+            .iter()
+            .filter_map(|day| Some((Date::parse(&day.date)?.unix_days(), day.count)))
+            .collect();
+        let Some(&last) = counts.keys().max() else {
+            return;
+        };
+
+        // Weekday labels, then one column (two when there is room) per week.
+        let label_width = 4;
+        let available = inner.width.saturating_sub(label_width) as usize;
+        let cell_width = if available >= MAX_WEEKS * 2 { 2 } else { 1 };
+        let weeks = (available / cell_width).min(MAX_WEEKS);
+        let last_sunday = last - i64::from(Date::from_unix_days(last).weekday());
+        let first_sunday = last_sunday - 7 * (weeks as i64 - 1);
+        let shown = (weeks as f64 * animation::value("activity.sweep", self.screen_tick).min(1.0))
+            .ceil() as usize;
+
+        // Center the labels and grid together in the panel.
+        let left = inner.x + (available - weeks * cell_width) as u16 / 2;
+        let grid_x = left + label_width;
+        let mut free_x = grid_x;
+        for week in 0..weeks {
+            let sunday = Date::from_unix_days(first_sunday + 7 * week as i64);
+            let x = grid_x + (week * cell_width) as u16;
+            // Label the first week of each month, if the previous label has ended.
+            if sunday.day <= 7 && x >= free_x && x + 3 <= inner.right() {
+                text_layout::draw(
+                    buf,
+                    inner,
+                    x,
+                    inner.y,
+                    sunday.month_name(),
+                    Style::new().fg(FG_MUTED),
+                );
+                free_x = x + 4;
+            }
+        }
+
+        for weekday in 0..7u16 {
+            let y = inner.y + 1 + weekday;
+            if y >= inner.bottom() {
+                break;
+            }
+            let label = match weekday {
+                1 => "Mon",
+                3 => "Wed",
+                5 => "Fri",
+                _ => "",
+            };
+            text_layout::draw(buf, inner, left, y, label, Style::new().fg(FG_MUTED));
+            for week in 0..shown {
+                let day = first_sunday + 7 * week as i64 + i64::from(weekday);
+                if day > last {
+                    continue;
+                }
+                let level = activity.level(counts.get(&day).copied().unwrap_or(0));
+                let x = grid_x + (week * cell_width) as u16;
+                text_layout::draw(buf, inner, x, y, "■", Style::new().fg(shade(level)));
+            }
+        }
+
+        // Legend under the grid, right-aligned.
+        let legend_y = inner.y + 8;
+        if legend_y < inner.bottom() {
+            let mut spans = vec![Span::styled("Less ", Style::new().fg(FG_MUTED))];
+            for level in 0..=4 {
+                spans.push(Span::styled("■ ", Style::new().fg(shade(level))));
+            }
+            spans.push(Span::styled("More", Style::new().fg(FG_MUTED)));
+            Paragraph::new(Line::from(spans))
+                .alignment(Alignment::Right)
+                .render(Rect::new(inner.x, legend_y, inner.width, 1), buf);
+        }
+    }
+}
+
+fn render_repos(activity: &Activity, area: Rect, buf: &mut Buffer) {
+    let rows: Vec<Row> = activity
+        .repos
+        .iter()
+        .map(|repo| {
+            Row::new(vec![
+                Cell::from(Span::styled(
+                    repo.name.clone(),
+                    Style::new().fg(ACCENT_BLUE).bold(),
+                )),
+                Cell::from(Span::styled(
+                    format!("★ {}", repo.stars),
+                    Style::new().fg(ACCENT_GOLD),
+                )),
+                Cell::from(Span::styled(
+                    repo.language.clone().unwrap_or_default(),
+                    Style::new().fg(FG_SECONDARY),
+                )),
+            ])
+        })
+        .collect();
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Fill(1),
+            Constraint::Length(7),
+            Constraint::Length(12),
+        ],
+    )
+    .header(Row::new(vec!["Repository", "Stars", "Language"]).style(Style::new().fg(FG_MUTED)))
+    .block(panel("Top Repositories".to_string(), ACCENT_BLUE));
+    Widget::render(table, area, buf);
+}
+
+fn render_languages(activity: &Activity, area: Rect, buf: &mut Buffer) {
+    let block = panel("Languages".to_string(), ACCENT_VIOLET);
+    let inner = block.inner(area);
+    block.render(area, buf);
+
+    let total: u64 = activity
+        .languages
+        .iter()
+        .map(|language| language.bytes)
+        .sum();
+    if total == 0 || inner.height == 0 {
+        return;
+    }
+    let mut shares: Vec<(&str, u64, Color)> = activity
+        .languages
+        .iter()
+        .zip(LANGUAGE_COLORS)
+        .map(|(language, color)| (language.name.as_str(), language.bytes, color))
+        .collect();
+    let other: u64 = activity
+        .languages
+        .iter()
+        .skip(LISTED_LANGUAGES)
+        .map(|language| language.bytes)
+        .sum();
+    if other > 0 {
+        shares.push(("Other", other, FG_MUTED));
+    }
+
+    // Stacked bar, each language's segment proportional to its bytes.
+    let width = inner.width as u64;
+    let mut bar = Vec::new();
+    let mut drawn = 0;
+    let mut cumulative = 0;
+    for (_, bytes, color) in &shares {
+        cumulative += bytes;
+        let end = (cumulative * width).div_ceil(total);
+        bar.push(Span::styled(
+            "█".repeat(end.saturating_sub(drawn) as usize),
+            Style::new().fg(*color),
+        ));
+        drawn = end;
+    }
+
+    let mut lines = vec![Line::from(bar), Line::default()];
+    for (name, bytes, color) in shares {
+        lines.push(Line::from(vec![
+            Span::styled("● ", Style::new().fg(color)),
+            Span::styled(text_layout::pad(name, 14), Style::new().fg(FG_PRIMARY)),
+            Span::styled(
+                format!("{:>5.1}%", bytes as f64 * 100.0 / total as f64),
+                Style::new().fg(FG_SECONDARY),
+            ),
+        ]));
+    }
+    Paragraph::new(lines).render(inner, buf);
+}
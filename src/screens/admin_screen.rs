@@ -0,0 +1,259 @@
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Margin},
+    prelude::*,
+    style::Stylize,
+    text::{Line, Span},
+    widgets::{Bar, BarChart, BarGroup, Block, BorderType, Borders, Paragraph, Row, Table},
+};
+
+use super::theme::*;
+use crate::admin::Dashboard;
+
+/// Hidden operator view summarizing usage of the service.
+pub struct AdminScreenWidget<'a> {
+    dashboard: &'a Dashboard,
+    page: usize,
+    total: usize,
+    global_tick: u64,
+}
+
+pub fn admin_screen(
+    dashboard: &Dashboard,
+    page: usize,
+    total: usize,
+    global_tick: u64,
+) -> AdminScreenWidget<'_> {
+    AdminScreenWidget {
+        dashboard,
+        page,
+        total,
+        global_tick,
+    }
+}
+
+fn panel(title: &str, accent: Color) -> Block<'_> {
+    Block::default()
+        .title(Span::styled(
+            format!("── {} ──", title),
+            Style::new().fg(accent).bold(),
+        ))
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::new().fg(BORDER_DIM))
+        .style(Style::new().bg(BG_PANEL))
+}
+
+fn format_dwell(ms: u64) -> String {
+    let secs = ms / 1000;
+    if secs >= 60 {
+        format!("{}m {:02}s", secs / 60, secs % 60)
+    } else {
+        format!("{}.{}s", secs, (ms % 1000) / 100)
+    }
+}
+
+impl Widget for AdminScreenWidget<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        buf.set_style(area, Style::new().bg(BG_CANVAS));
+
+        let content = area.inner(Margin {
+            horizontal: 2,
+            vertical: 1,
+        });
+
+        if content.width < 40 || content.height < 12 {
+            return;
+        }
+
+        let sections = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(3),
+                Constraint::Min(10),
+                Constraint::Length(5),
+            ])
+            .split(content);
+
+        self.render_header(sections[0], buf);
+
+        buf.set_style(sections[1], Style::new().bg(BG_SECTION));
+        let columns = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([
+                Constraint::Percentage(55),
+                Constraint::Length(1), // spacer
+                Constraint::Percentage(45),
+            ])
+            .split(sections[1]);
+        let left = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Percentage(55), Constraint::Percentage(45)])
+            .split(columns[0]);
+
+        self.render_sessions_chart(left[0], buf);
+        self.render_screens_table(left[1], buf);
+        self.render_resources(columns[2], buf);
+
+        render_footer(
+            sections[2],
+            buf,
+            self.page,
+            self.total,
+            "Operator view · refreshes every 5s · A to leave",
+        );
+    }
+}
+
+impl AdminScreenWidget<'_> {
+    fn render_header(&self, area: Rect, buf: &mut Buffer) {
+        let dashboard = self.dashboard;
+        let today = dashboard
+            .sessions_per_day
+            .last()
+            .map_or(0, |(_, count)| *count);
+        let stat = |label: &'static str, value: String, accent: Color| {
+            vec![
+                Span::styled(label, Style::new().fg(FG_SECONDARY)),
+                Span::styled(value, Style::new().fg(accent).bold()),
+                Span::styled("   ·   ", Style::new().fg(FG_DIM)),
+            ]
+        };
+        let mut spans = Vec::new();
+        spans.extend(stat(
+            "active ",
+            dashboard.active_sessions.to_string(),
+            pulsing_accent(ACCENT_GREEN, self.global_tick, 10),
+        ));
+        spans.extend(stat("today ", today.to_string(), ACCENT_TEAL));
+        spans.extend(stat(
+            "all time ",
+            dashboard.total_sessions.to_string(),
+            ACCENT_GOLD,
+        ));
+        spans.pop();
+
+        let header = Paragraph::new(vec![Line::from(spans)])
+            .alignment(Alignment::Center)
+            .block(
+                Block::default()
+                    .title(Span::styled(
+                        "── Operator Console ──",
+                        Style::new().fg(ACCENT_RED).bold(),
+                    ))
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+                    .border_style(Style::new().fg(BORDER_ACCENT))
+                    .style(Style::new().bg(BG_HERO)),
+            );
+        header.render(area, buf);
+    }
+
+    fn render_sessions_chart(&self, area: Rect, buf: &mut Buffer) {
+        let bars: Vec<Bar> = self
+            .dashboard
+            .sessions_per_day
+            .iter()
+            .map(|(date, count)| {
+                Bar::default()
+                    .value(*count)
+                    .label(Line::from(date.short_label()))
+                    .style(Style::new().fg(ACCENT_TEAL))
+                    .value_style(Style::new().fg(BG_CANVAS).bg(ACCENT_TEAL).bold())
+            })
+            .collect();
+        let inner_width = area.width.saturating_sub(2);
+        let count = bars.len().max(1) as u16;
+        let bar_width = (inner_width / count).saturating_sub(1).clamp(1, 9);
+
+        BarChart::default()
+            .block(panel("Sessions per Day", ACCENT_TEAL))
+            .data(BarGroup::default().bars(&bars))
+            .bar_width(bar_width)
+            .bar_gap(1)
+            .label_style(Style::new().fg(FG_MUTED))
+            .render(area, buf);
+    }
+
+    fn render_screens_table(&self, area: Rect, buf: &mut Buffer) {
+        let rows: Vec<Row> = self
+            .dashboard
+            .screens
+            .iter()
+            .map(|(name, visits, avg_dwell)| {
+                Row::new(vec![
+                    Span::styled(name.as_str(), Style::new().fg(FG_PRIMARY)),
+                    Span::styled(visits.to_string(), Style::new().fg(ACCENT_GOLD)),
+                    Span::styled(format_dwell(*avg_dwell), Style::new().fg(ACCENT_BLUE)),
+                ])
+            })
+            .collect();
+
+        let table = Table::new(
+            rows,
+            [
+                Constraint::Percentage(45),
+                Constraint::Percentage(20),
+                Constraint::Percentage(35),
+            ],
+        )
+        .header(
+            Row::new(vec!["screen", "visits", "avg dwell"])
+                .style(Style::new().fg(FG_MUTED).italic()),
+        )
+        .block(panel("Popular Screens", ACCENT_GOLD));
+        Widget::render(table, area, buf);
+    }
+
+    fn render_resources(&self, area: Rect, buf: &mut Buffer) {
+        let resources = &self.dashboard.resources;
+        let na = || "n/a".to_string();
+        let line = |label: &'static str, value: String| {
+            Line::from(vec![
+                Span::styled(format!("{:<10}", label), Style::new().fg(FG_SECONDARY)),
+                Span::styled(value, Style::new().fg(FG_PRIMARY)),
+            ])
+        };
+
+        let lines = vec![
+            line(
+                "load",
+                resources.load.map_or_else(na, |[one, five, fifteen]| {
+                    format!("{:.2}  {:.2}  {:.2}", one, five, fifteen)
+                }),
+            ),
+            line(
+                "memory",
+                match (resources.mem_used_kb, resources.mem_total_kb) {
+                    (Some(used), Some(total)) => format!(
+                        "{} / {} MiB ({}%)",
+                        used / 1024,
+                        total / 1024,
+                        used * 100 / total.max(1)
+                    ),
+                    _ => na(),
+                },
+            ),
+            line(
+                "session",
+                resources
+                    .process_rss_kb
+                    .map_or_else(na, |rss| format!("{:.1} MiB RSS", rss as f64 / 1024.0)),
+            ),
+            line(
+                "uptime",
+                resources.uptime_secs.map_or_else(na, |secs| {
+                    format!(
+                        "{}d {}h {}m",
+                        secs / 86_400,
+                        secs / 3600 % 24,
+                        secs / 60 % 60
+                    )
+                }),
+            ),
+        ];
+
+        Paragraph::new(lines)
+            .block(panel("Server", ACCENT_VIOLET))
+            .render(area, buf);
+    }
+}
@@ -0,0 +1,195 @@
+//! Declarative reveal and motion timings shared by every screen.
+//!
+//! Widgets never hard-code tick thresholds; they look an animation up by name and ask how far
+//! along it is for the current `screen_tick`. Retuning a reveal means editing [`ANIMATIONS`].
+
+/// Easing curve applied to an animation's linear progress.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Easing {
+    Linear,
+    /// Cubic ease-in-out: slow start, fast middle, slow finish.
+    EaseInOut,
+    /// Cubic ease-out: fast start that decelerates into place.
+    Cubic,
+    /// Under-damped spring that overshoots the target before settling on it.
+    Spring,
+}
+
+impl Easing {
+    /// Map linear progress `t` in [0.0, 1.0] onto the curve.
+    pub fn apply(self, t: f64) -> f64 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            Easing::Linear => t,
+            Easing::EaseInOut => {
+                if t < 0.5 {
+                    4.0 * t * t * t
+                } else {
+                    1.0 - (-2.0 * t + 2.0).powi(3) / 2.0
+                }
+            }
+            Easing::Cubic => 1.0 - (1.0 - t).powi(3),
+            Easing::Spring => {
+                if t >= 1.0 {
+                    1.0
+                } else {
+                    1.0 - (-6.0 * t).exp() * (t * std::f64::consts::PI * 2.5).cos()
+                }
+            }
+        }
+    }
+}
+
+/// A value the animation passes through at eased progress `at`.
+#[derive(Clone, Copy, Debug)]
+pub struct Keyframe {
+    pub at: f64,
+    pub value: f64,
+}
+
+const fn key(at: f64, value: f64) -> Keyframe {
+    Keyframe { at, value }
+}
+
+/// The default 0 → 1 ramp.
+const RAMP: &[Keyframe] = &[key(0.0, 0.0), key(1.0, 1.0)];
+
+/// Fake "loading" curve for the intro status bar: rush to 70%, stall, then finish.
+const LOADING: &[Keyframe] = &[key(0.0, 0.0), key(0.4, 0.7), key(0.7, 0.7), key(1.0, 1.0)];
+
+/// Timing for one named animation, in app ticks.
+#[derive(Clone, Copy, Debug)]
+pub struct Animation {
+    /// Ticks after the screen is entered before the animation starts.
+    pub delay: u64,
+    /// Ticks from start until the final keyframe is reached. Zero means "appear instantly".
+    pub duration: u64,
+    /// Extra delay added per item index for staggered lists.
+    pub stagger: u64,
+    pub easing: Easing,
+    pub keyframes: &'static [Keyframe],
+}
+
+impl Animation {
+    /// An animation that simply appears at `delay`.
+    const fn reveal(delay: u64) -> Self {
+        Self::tween(delay, 0, Easing::Linear)
+    }
+
+    /// A 0 → 1 tween starting at `delay` and lasting `duration` ticks.
+    const fn tween(delay: u64, duration: u64, easing: Easing) -> Self {
+        Self {
+            delay,
+            duration,
+            stagger: 0,
+            easing,
+            keyframes: RAMP,
+        }
+    }
+
+    const fn staggered(mut self, stagger: u64) -> Self {
+        self.stagger = stagger;
+        self
+    }
+
+    const fn keyframes(mut self, keyframes: &'static [Keyframe]) -> Self {
+        self.keyframes = keyframes;
+        self
+    }
+
+    /// Has item `index` of this animation started by `tick`?
+    pub fn started_at(&self, tick: u64, index: usize) -> bool {
+        tick >= self.delay + self.stagger * index as u64
+    }
+
+    /// Is the animation between its start and its final keyframe?
+    pub fn running(&self, tick: u64) -> bool {
+        self.started_at(tick, 0) && tick < self.delay + self.duration
+    }
+
+    /// Eased progress in [0.0, 1.0] (springs may briefly exceed 1.0).
+    pub fn progress(&self, tick: u64) -> f64 {
+        if tick < self.delay {
+            return 0.0;
+        }
+        if self.duration == 0 {
+            return 1.0;
+        }
+        let linear = (tick - self.delay) as f64 / self.duration as f64;
+        self.easing.apply(linear)
+    }
+
+    /// Value of the keyframe track at `tick`.
+    pub fn value(&self, tick: u64) -> f64 {
+        sample(self.keyframes, self.progress(tick))
+    }
+}
+
+/// Linearly interpolate `keyframes` at `t`, extrapolating past the last pair so springs overshoot.
+fn sample(keyframes: &[Keyframe], t: f64) -> f64 {
+    let Some(first) = keyframes.first() else {
+        return t;
+    };
+    if t <= first.at {
+        return first.value;
+    }
+    let pair = keyframes
+        .windows(2)
+        .find(|pair| t <= pair[1].at)
+        .or_else(|| keyframes.windows(2).last());
+    match pair {
+        Some([a, b]) if b.at > a.at => a.value + (b.value - a.value) * (t - a.at) / (b.at - a.at),
+        _ => first.value,
+    }
+}
+
+/// Every named animation used by the screens.
+pub const ANIMATIONS: &[(&str, Animation)] = &[
+    // Intro
+    (
+        "intro.status",
+        Animation::tween(0, 10, Easing::Linear).keyframes(LOADING),
+    ),
+    ("intro.hero", Animation::reveal(2)),
+    ("intro.hero.fade", Animation::tween(2, 4, Easing::EaseInOut)),
+    ("intro.hero.detail", Animation::reveal(5)),
+    ("intro.highlights", Animation::reveal(8)),
+    ("intro.card", Animation::reveal(8).staggered(2)),
+    // Skills
+    ("skills.left", Animation::reveal(2)),
+    ("skills.right", Animation::reveal(6)),
+    ("skills.gauge", Animation::tween(0, 15, Easing::Spring)),
+    // Experience
+    ("experience.entry", Animation::reveal(2).staggered(2)),
+    (
+        "experience.attention",
+        Animation::tween(0, 20, Easing::Linear),
+    ),
+    // Telemetry
+    ("telemetry.count_up", Animation::tween(0, 8, Easing::Cubic)),
+    // Activity
+    ("activity.sweep", Animation::tween(0, 8, Easing::Cubic)),
+];
+
+/// Animation used when a name is missing from [`ANIMATIONS`]: already finished.
+const INSTANT: Animation = Animation::reveal(0);
+
+/// Look up an animation by name.
+pub fn get(name: &str) -> &'static Animation {
+    let found = ANIMATIONS
+        .iter()
+        .find(|(key, _)| *key == name)
+        .map(|(_, animation)| animation);
+    debug_assert!(found.is_some(), "unknown animation `{name}`");
+    found.unwrap_or(&INSTANT)
+}
+
+/// Has the named animation started by `tick`?
+pub fn started(name: &str, tick: u64) -> bool {
+    get(name).started_at(tick, 0)
+}
+
+/// Value of the named animation at `tick`, normally in [0.0, 1.0].
+pub fn value(name: &str, tick: u64) -> f64 {
+    get(name).value(tick)
+}
@@ -0,0 +1,240 @@
+use ratatui::{
+    layout::{Constraint, Direction, Flex, Layout, Margin},
+    prelude::*,
+    style::Stylize,
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Clear, Paragraph, Row, Table},
+};
+
+use super::text_layout;
+use super::theme::*;
+use crate::arcade::{Arcade, BOARD_HEIGHT, BOARD_WIDTH, MAX_HANDLE_LEN, Phase};
+
+/// Snake playfield with the shared high-score table beside it.
+pub struct ArcadeScreenWidget<'a> {
+    arcade: &'a Arcade,
+    page: usize,
+    total: usize,
+    global_tick: u64,
+}
+
+pub fn arcade_screen(
+    arcade: &Arcade,
+    page: usize,
+    total: usize,
+    global_tick: u64,
+) -> ArcadeScreenWidget<'_> {
+    ArcadeScreenWidget {
+        arcade,
+        page,
+        total,
+        global_tick,
+    }
+}
+
+fn panel(title: &str, accent: Color) -> Block<'_> {
+    Block::default()
+        .title(Span::styled(
+            format!("── {} ──", title),
+            Style::new().fg(accent).bold(),
+        ))
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::new().fg(BORDER_DIM))
+        .style(Style::new().bg(BG_PANEL))
+}
+
+impl Widget for ArcadeScreenWidget<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        buf.set_style(area, Style::new().bg(BG_CANVAS));
+
+        let content = area.inner(Margin {
+            horizontal: 2,
+            vertical: 1,
+        });
+
+        let sections = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(BOARD_HEIGHT + 2), Constraint::Length(5)])
+            .split(content);
+
+        let board_width = BOARD_WIDTH * 2 + 2;
+        let [row] = Layout::vertical([Constraint::Length(BOARD_HEIGHT + 2)])
+            .flex(Flex::Center)
+            .areas(sections[0]);
+        let columns = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([
+                Constraint::Length(board_width),
+                Constraint::Length(2), // spacer
+                Constraint::Length(34),
+            ])
+            .flex(Flex::Center)
+            .split(row);
+
+        buf.set_style(sections[0], Style::new().bg(BG_SECTION));
+        if columns[0].width < board_width || columns[0].height < BOARD_HEIGHT + 2 {
+            Paragraph::new(Line::from(Span::styled(
+                "Enlarge the terminal to play.",
+                Style::new().fg(FG_MUTED).italic(),
+            )))
+            .alignment(Alignment::Center)
+            .render(sections[0], buf);
+        } else {
+            self.render_board(columns[0], buf);
+            self.render_scores(columns[2], buf);
+        }
+
+        render_footer(
+            sections[1],
+            buf,
+            self.page,
+            self.total,
+            "Arrows / hjkl steer · space starts a new run",
+        );
+    }
+}
+
+impl ArcadeScreenWidget<'_> {
+    fn render_board(&self, board: Rect, buf: &mut Buffer) {
+        let game = &self.arcade.game;
+        let block = panel("Snake", ACCENT_GREEN).title_bottom(Line::from(Span::styled(
+            format!(" score {} ", game.score),
+            Style::new().fg(ACCENT_GOLD).bold(),
+        )));
+        let inner = block.inner(board);
+        block.render(board, buf);
+
+        let cell = |(x, y): (u16, u16)| Rect::new(inner.x + x * 2, inner.y + y, 2, 1);
+        for y in 0..BOARD_HEIGHT {
+            for x in 0..BOARD_WIDTH {
+                text_layout::draw(
+                    buf,
+                    inner,
+                    inner.x + x * 2,
+                    inner.y + y,
+                    "· ",
+                    Style::new().fg(BG_HERO),
+                );
+            }
+        }
+        text_layout::draw(
+            buf,
+            inner,
+            cell(game.food).x,
+            cell(game.food).y,
+            "◆ ",
+            Style::new().fg(pulsing_accent(ACCENT_GOLD, self.global_tick, 6)),
+        );
+        for (index, &segment) in game.body.iter().enumerate() {
+            let color = if index == 0 {
+                ACCENT_TEAL
+            } else {
+                color_lerp(
+                    ACCENT_GREEN,
+                    BORDER_ACCENT,
+                    index as f64 / game.body.len() as f64,
+                )
+            };
+            buf.set_style(cell(segment), Style::new().bg(color));
+        }
+
+        let message: Option<Vec<Line>> = match (&self.arcade.entry, game.phase) {
+            (Some(handle), _) => Some(vec![
+                Line::from(Span::styled(
+                    format!("New high score: {}!", game.score),
+                    Style::new().fg(ACCENT_GOLD).bold(),
+                )),
+                Line::from(vec![
+                    Span::styled("handle ", Style::new().fg(FG_SECONDARY)),
+                    Span::styled(
+                        text_layout::pad(&format!("{handle}▌"), MAX_HANDLE_LEN + 1),
+                        Style::new().fg(ACCENT_TEAL).bold(),
+                    ),
+                ]),
+                Line::from(Span::styled(
+                    "enter saves · esc skips",
+                    Style::new().fg(FG_MUTED).italic(),
+                )),
+            ]),
+            (None, Phase::Ready) => Some(vec![Line::from(Span::styled(
+                "Press space to start",
+                Style::new().fg(pulsing_accent(ACCENT_TEAL, self.global_tick, 8)),
+            ))]),
+            (None, Phase::Over) => Some(vec![
+                Line::from(Span::styled(
+                    format!("Game over · score {}", game.score),
+                    Style::new().fg(ACCENT_RED).bold(),
+                )),
+                Line::from(Span::styled(
+                    "space to play again",
+                    Style::new().fg(FG_MUTED).italic(),
+                )),
+            ]),
+            (None, Phase::Playing) => None,
+        };
+        if let Some(lines) = message {
+            let [row] = Layout::vertical([Constraint::Length(lines.len() as u16 + 2)])
+                .flex(Flex::Center)
+                .areas(inner);
+            let [card] = Layout::horizontal([Constraint::Length(32)])
+                .flex(Flex::Center)
+                .areas(row);
+            Clear.render(card, buf);
+            Paragraph::new(lines)
+                .alignment(Alignment::Center)
+                .block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .border_type(BorderType::Rounded)
+                        .border_style(Style::new().fg(BORDER_ACCENT))
+                        .style(Style::new().bg(BG_HERO)),
+                )
+                .render(card, buf);
+        }
+    }
+
+    fn render_scores(&self, area: Rect, buf: &mut Buffer) {
+        let scores = &self.arcade.scores;
+        let block = panel("High Scores", ACCENT_GOLD);
+        if scores.is_empty() {
+            Paragraph::new(Line::from(Span::styled(
+                "No scores yet. Be the first!",
+                Style::new().fg(FG_MUTED).italic(),
+            )))
+            .block(block)
+            .render(area, buf);
+            return;
+        }
+
+        let rows: Vec<Row> = scores
+            .iter()
+            .enumerate()
+            .map(|(rank, entry)| {
+                let accent = match rank {
+                    0 => ACCENT_GOLD,
+                    1 => FG_PRIMARY,
+                    2 => ACCENT_VIOLET,
+                    _ => FG_SECONDARY,
+                };
+                Row::new(vec![
+                    Span::styled(format!("{:>2}.", rank + 1), Style::new().fg(FG_MUTED)),
+                    Span::styled(entry.handle.as_str(), Style::new().fg(accent).bold()),
+                    Span::styled(entry.score.to_string(), Style::new().fg(ACCENT_TEAL)),
+                ])
+            })
+            .collect();
+
+        let table = Table::new(
+            rows,
+            [
+                Constraint::Length(4),
+                Constraint::Min(MAX_HANDLE_LEN as u16),
+                Constraint::Length(6),
+            ],
+        )
+        .header(Row::new(vec!["", "handle", "score"]).style(Style::new().fg(FG_MUTED).italic()))
+        .block(block);
+        Widget::render(table, area, buf);
+    }
+}
@@ -0,0 +1,155 @@
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Margin},
+    prelude::*,
+    style::Stylize,
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Padding, Paragraph},
+};
+
+use super::{markdown, text_layout, theme::*};
+use crate::blog::Blog;
+
+/// Post list, or the open post rendered from Markdown.
+pub struct BlogScreenWidget<'a> {
+    blog: &'a Blog,
+    page: usize,
+    total: usize,
+}
+
+pub fn blog_screen(blog: &Blog, page: usize, total: usize) -> BlogScreenWidget<'_> {
+    BlogScreenWidget { blog, page, total }
+}
+
+fn panel(title: String, accent: Color) -> Block<'static> {
+    Block::default()
+        .title(Span::styled(
+            format!("── {} ──", title),
+            Style::new().fg(accent).bold(),
+        ))
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::new().fg(BORDER_DIM))
+        .padding(Padding::horizontal(1))
+        .style(Style::new().bg(BG_PANEL))
+}
+
+impl Widget for BlogScreenWidget<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        buf.set_style(area, Style::new().bg(BG_CANVAS));
+
+        let content = area.inner(Margin {
+            horizontal: 2,
+            vertical: 1,
+        });
+
+        if content.width < 30 || content.height < 10 {
+            return;
+        }
+
+        let sections = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(5), Constraint::Length(5)])
+            .split(content);
+
+        let hint = match self.blog.selected_post() {
+            Some(_) if self.blog.reading => {
+                self.render_post(sections[0], buf);
+                "j/k or PgUp/PgDn to scroll · g/G top/bottom · Esc back to posts"
+            }
+            _ => {
+                self.render_list(sections[0], buf);
+                "j/k to choose · Enter to read"
+            }
+        };
+        render_footer(sections[1], buf, self.page, self.total, hint);
+    }
+}
+
+impl BlogScreenWidget<'_> {
+    fn render_list(&self, area: Rect, buf: &mut Buffer) {
+        let block = panel(
+            format!("Notes · {} posts", self.blog.posts.len()),
+            ACCENT_TEAL,
+        );
+        let inner = block.inner(area);
+        block.render(area, buf);
+
+        if self.blog.posts.is_empty() {
+            Paragraph::new(Span::styled(
+                "Nothing published yet.",
+                Style::new().fg(FG_MUTED).italic(),
+            ))
+            .render(inner, buf);
+            return;
+        }
+
+        // Each post takes a title row, a meta row and a spacer.
+        let per_post = 3;
+        let visible = (inner.height as usize / per_post).max(1);
+        let first = (self.blog.selected + 1).saturating_sub(visible);
+
+        let mut lines = Vec::new();
+        for (index, post) in self.blog.posts.iter().enumerate().skip(first).take(visible) {
+            let selected = index == self.blog.selected;
+            let (marker, title_style) = if selected {
+                ("▶ ", Style::new().fg(ACCENT_GOLD).bold())
+            } else {
+                ("  ", Style::new().fg(FG_PRIMARY))
+            };
+            lines.push(Line::from(vec![
+                Span::styled(marker, Style::new().fg(ACCENT_GOLD)),
+                Span::styled(post.title.clone(), title_style),
+            ]));
+
+            let mut meta = vec![
+                Span::raw("  "),
+                Span::styled(post.date.clone(), Style::new().fg(FG_MUTED)),
+            ];
+            for tag in &post.tags {
+                meta.push(Span::raw("  "));
+                meta.push(Span::styled(
+                    format!("#{tag}"),
+                    Style::new().fg(ACCENT_VIOLET),
+                ));
+            }
+            lines.push(Line::from(meta));
+            lines.push(Line::default());
+        }
+        Paragraph::new(lines).render(inner, buf);
+    }
+
+    fn render_post(&self, area: Rect, buf: &mut Buffer) {
+        let Some(post) = self.blog.selected_post() else {
+            return;
+        };
+        let block = panel(post.title.clone(), ACCENT_TEAL).title_bottom(
+            Line::from(Span::styled(
+                format!(" {} ", post.date),
+                Style::new().fg(FG_MUTED),
+            ))
+            .right_aligned(),
+        );
+        let inner = block.inner(area);
+        block.render(area, buf);
+
+        let lines = markdown::render(&post.body, inner.width as usize);
+        let scroll = self.blog.scroll.fit(lines.len(), inner.height as usize);
+
+        Paragraph::new(lines.into_iter().skip(scroll).collect::<Vec<_>>()).render(inner, buf);
+
+        if let Some(percent) = (scroll * 100).checked_div(self.blog.scroll.max()) {
+            let label = format!(" {percent}% ");
+            let x = area
+                .right()
+                .saturating_sub(text_layout::width(&label) as u16 + 2);
+            text_layout::draw(
+                buf,
+                area,
+                x,
+                area.y,
+                &label,
+                Style::new().fg(FG_MUTED).bg(BG_PANEL),
+            );
+        }
+    }
+}
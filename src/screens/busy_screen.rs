@@ -0,0 +1,70 @@
+use ratatui::{
+    layout::{Constraint, Flex, Layout},
+    prelude::*,
+    style::Stylize,
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Padding, Paragraph, Wrap},
+};
+
+use super::theme::*;
+use crate::admission::Rejection;
+
+/// Shown instead of the portfolio when a connection is turned away.
+pub struct BusyScreenWidget {
+    rejection: Rejection,
+}
+
+pub fn busy_screen(rejection: Rejection) -> BusyScreenWidget {
+    BusyScreenWidget { rejection }
+}
+
+impl Widget for BusyScreenWidget {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        buf.set_style(area, Style::new().bg(BG_CANVAS));
+
+        let [row] = Layout::vertical([Constraint::Length(10)])
+            .flex(Flex::Center)
+            .areas(area);
+        let [card] = Layout::horizontal([Constraint::Max(60)])
+            .flex(Flex::Center)
+            .areas(row);
+
+        let reason = match self.rejection {
+            Rejection::ServerBusy => "The server is at capacity right now.",
+            Rejection::SourceBusy => "You already have the maximum number of sessions open.",
+            Rejection::RateLimited => "Too many recent connections from your address.",
+        };
+
+        let lines = vec![
+            Line::from(Span::styled(reason, Style::new().fg(FG_PRIMARY))),
+            Line::default(),
+            Line::from(Span::styled(
+                "Please try again in a minute.",
+                Style::new().fg(ACCENT_TEAL),
+            )),
+            Line::default(),
+            Line::from(Span::styled(
+                "Press any key to disconnect.",
+                Style::new().fg(FG_MUTED).italic(),
+            )),
+        ];
+
+        Paragraph::new(lines)
+            .alignment(Alignment::Center)
+            .wrap(Wrap { trim: true })
+            .block(
+                Block::default()
+                    .title(Span::styled(
+                        "── Server Busy ──",
+                        Style::new().fg(ACCENT_GOLD).bold(),
+                    ))
+                    .title_alignment(Alignment::Center)
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+                    .border_style(Style::new().fg(BORDER_ACCENT))
+                    .padding(Padding::uniform(1))
+                    .style(Style::new().bg(BG_HERO)),
+            )
+            .render(card, buf);
+    }
+}
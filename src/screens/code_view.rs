@@ -0,0 +1,93 @@
+use ratatui::{
+    prelude::*,
+    style::Stylize,
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Paragraph},
+};
+
+use super::theme::*;
+use crate::highlight::{self, Language};
+use crate::projects::Projects;
+
+/// The selected project's source files with line numbers and syntax highlighting.
+pub struct CodeViewWidget<'a> {
+    projects: &'a Projects,
+}
+
+pub fn code_view(projects: &Projects) -> CodeViewWidget<'_> {
+    CodeViewWidget { projects }
+}
+
+impl Widget for CodeViewWidget<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let project = self.projects.project();
+        let Some(snippet) = self.projects.snippet() else {
+            return;
+        };
+        let language = Language::from_path(&snippet.file);
+
+        // One tab per file in the title, the open one highlighted.
+        let mut tabs = vec![Span::styled("── ", Style::new().fg(project.accent))];
+        for (index, other) in self.projects.snippets().iter().enumerate() {
+            if index == self.projects.file {
+                tabs.push(Span::styled(
+                    format!(" {} ", other.file),
+                    Style::new().fg(BG_CANVAS).bg(project.accent).bold(),
+                ));
+            } else {
+                tabs.push(Span::styled(
+                    format!(" {} ", other.file),
+                    Style::new().fg(FG_MUTED),
+                ));
+            }
+        }
+        tabs.push(Span::styled(
+            format!(" · {} ──", language.label()),
+            Style::new().fg(project.accent),
+        ));
+
+        let block = Block::default()
+            .title(Line::from(tabs))
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::new().fg(BORDER_ACCENT))
+            .style(Style::new().bg(BG_CARD));
+        let inner = block.inner(area);
+
+        let source = highlight::highlight(&snippet.source.replace('\t', "    "), language);
+        let visible = inner.height as usize;
+        let scroll = self.projects.scroll.fit(source.len(), visible);
+
+        let position = format!(
+            " {}–{} of {} ",
+            scroll + 1,
+            (scroll + visible).min(source.len()),
+            source.len()
+        );
+        block
+            .title_bottom(
+                Line::from(Span::styled(position, Style::new().fg(FG_MUTED))).right_aligned(),
+            )
+            .render(area, buf);
+
+        let gutter = source.len().to_string().len();
+        let lines: Vec<Line> = source
+            .into_iter()
+            .enumerate()
+            .skip(scroll)
+            .take(visible)
+            .map(|(index, runs)| {
+                let mut spans = vec![
+                    Span::styled(format!(" {:>gutter$} ", index + 1), Style::new().fg(FG_DIM)),
+                    Span::styled("│ ", Style::new().fg(BORDER_DIM)),
+                ];
+                spans.extend(
+                    runs.into_iter()
+                        .map(|(token, text)| Span::styled(text, token_style(token))),
+                );
+                Line::from(spans)
+            })
+            .collect();
+        Paragraph::new(lines).render(inner, buf);
+    }
+}
@@ -0,0 +1,121 @@
+use std::time::Duration;
+
+use ratatui::{
+    prelude::*,
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Clear, Padding, Paragraph},
+};
+
+use super::theme::*;
+use crate::debug::FrameStats;
+
+const WIDTH: u16 = 34;
+
+/// Frame timing card pinned to the top-right corner, drawn over the current screen.
+pub struct DebugOverlayWidget<'a> {
+    stats: &'a FrameStats,
+    since_input: Duration,
+    tick_rate: Duration,
+    screen_tick: u64,
+    global_tick: u64,
+}
+
+pub fn debug_overlay(
+    stats: &FrameStats,
+    since_input: Duration,
+    tick_rate: Duration,
+    screen_tick: u64,
+    global_tick: u64,
+) -> DebugOverlayWidget<'_> {
+    DebugOverlayWidget {
+        stats,
+        since_input,
+        tick_rate,
+        screen_tick,
+        global_tick,
+    }
+}
+
+fn millis(duration: Duration) -> String {
+    format!("{:.2} ms", duration.as_secs_f64() * 1000.0)
+}
+
+fn row(label: &str, value: String, color: Color) -> Line<'static> {
+    Line::from(vec![
+        Span::styled(format!("{label:<11}"), Style::new().fg(FG_MUTED)),
+        Span::styled(value, Style::new().fg(color)),
+    ])
+}
+
+impl Widget for DebugOverlayWidget<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let stats = self.stats;
+        let last = stats.last_frame();
+        // A frame that misses the transition cadence (~30 fps) is worth noticing.
+        let frame_color = match last.as_millis() {
+            0..16 => ACCENT_GREEN,
+            16..33 => ACCENT_GOLD,
+            _ => ACCENT_RED,
+        };
+        let measured = stats
+            .tick_rate()
+            .map_or("–".to_string(), |rate| format!("{rate:.1}/s"));
+        let lines = vec![
+            row("frame", millis(last), frame_color),
+            row(
+                "avg / max",
+                format!(
+                    "{} / {}",
+                    millis(stats.mean_frame()),
+                    millis(stats.worst_frame())
+                ),
+                FG_SECONDARY,
+            ),
+            row("cells", stats.cells_changed.to_string(), FG_PRIMARY),
+            row(
+                "idle",
+                format!("{:.1} s", self.since_input.as_secs_f64()),
+                FG_PRIMARY,
+            ),
+            row(
+                "tick",
+                format!("{} ms ({measured})", self.tick_rate.as_millis()),
+                FG_PRIMARY,
+            ),
+            row(
+                "size",
+                format!("{}×{}", area.width, area.height),
+                FG_PRIMARY,
+            ),
+            row("color", stats.color_depth.label().to_string(), FG_PRIMARY),
+            row(
+                "ticks",
+                format!("{} / {}", self.screen_tick, self.global_tick),
+                FG_PRIMARY,
+            ),
+        ];
+
+        let height = lines.len() as u16 + 2;
+        let card = Rect {
+            x: area.right().saturating_sub(WIDTH + 1).max(area.x),
+            y: area.y + 1.min(area.height),
+            width: WIDTH.min(area.width),
+            height: height.min(area.height.saturating_sub(1)),
+        };
+        Clear.render(card, buf);
+        Paragraph::new(lines)
+            .block(
+                Block::default()
+                    .title(Span::styled(
+                        " Debug · F12 ",
+                        Style::new().fg(ACCENT_VIOLET).bold(),
+                    ))
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+                    .border_style(Style::new().fg(ACCENT_VIOLET))
+                    .padding(Padding::horizontal(1))
+                    .style(Style::new().bg(BG_HERO)),
+            )
+            .render(card, buf);
+    }
+}
@@ -3,26 +3,37 @@ use ratatui::{
     prelude::*,
     style::Stylize,
     text::{Line, Span},
-    widgets::{Block, BorderType, Borders, Padding, Paragraph, Wrap},
+    widgets::{
+        Bar, BarChart, BarGroup, Block, BorderType, Borders, Padding, Paragraph, Wrap,
+        canvas::{Canvas, Line as CanvasLine},
+    },
 };
 
+use super::animation;
+use super::text_layout;
 use super::theme::*;
+use crate::calendar::{self, Month};
+use crate::content::{EMAIL, SKILLS, Skill, SkillCategory, TAGLINE};
+use crate::skills::{SkillView, Skills};
 
-pub struct FirstScreenWidget {
+pub struct FirstScreenWidget<'a> {
     call_sign: String,
+    skills: &'a Skills,
     page: usize,
     total: usize,
     screen_tick: u64,
 }
 
-pub fn first_screen(
+pub fn first_screen<'a>(
     call_sign: &str,
+    skills: &'a Skills,
     page: usize,
     total: usize,
     screen_tick: u64,
-) -> FirstScreenWidget {
+) -> FirstScreenWidget<'a> {
     FirstScreenWidget {
         call_sign: call_sign.to_string(),
+        skills,
         page,
         total,
         screen_tick,
@@ -33,9 +44,8 @@ pub fn first_screen(
 fn render_skill_gauge(
     buf: &mut Buffer,
     area: Rect,
-    name: &str,
-    pct: u16,
-    accent: Color,
+    skill: &Skill,
+    selected: bool,
     y_offset: u16,
     screen_tick: u64,
 ) {
@@ -45,78 +55,195 @@ fn render_skill_gauge(
 
     let label_y = area.y + y_offset;
     let bar_y = area.y + y_offset + 1;
-    let inner_x = area.x + 1;
-    let inner_w = area.width.saturating_sub(2);
+    let inner = area.inner(Margin {
+        horizontal: 1,
+        vertical: 0,
+    });
+    let inner_w = inner.width as usize;
 
     if inner_w < 10 {
         return;
     }
 
-    // Animate fill from 0% to target over 15 ticks
-    let animated_pct = if screen_tick < 15 {
-        (pct as u64 * screen_tick / 15) as u16
-    } else {
-        pct
-    };
+    // Animate fill from 0% to target; the spring may briefly overshoot but never past 100%
+    let fill = animation::value("skills.gauge", screen_tick).max(0.0);
+    let animated_pct = ((skill.level as f64 * fill).round() as u16).min(100);
+    let accent = skill.accent;
 
-    // Label line: ◆ name ····· pct%
+    // Label line: ◆ name ····· pct%, the name giving way first on narrow panels
     let pct_str = format!("{}%", animated_pct);
-    let prefix = format!("◆ {} ", name);
-    let dots_len = inner_w as usize - prefix.len() - pct_str.len();
-
-    let mut x = inner_x;
-    for ch in prefix.chars() {
-        if x < area.x + area.width {
-            buf[(x, label_y)].set_char(ch);
-            buf[(x, label_y)].set_style(Style::new().fg(accent).bold());
-            x += 1;
-        }
-    }
-    for _ in 0..dots_len {
-        if x < area.x + area.width {
-            buf[(x, label_y)].set_char('·');
-            buf[(x, label_y)].set_style(Style::new().fg(FG_DIM));
-            x += 1;
-        }
-    }
-    for ch in pct_str.chars() {
-        if x < area.x + area.width {
-            buf[(x, label_y)].set_char(ch);
-            buf[(x, label_y)].set_style(Style::new().fg(FG_PRIMARY));
-            x += 1;
-        }
-    }
+    let name_room = inner_w.saturating_sub(text_layout::width(&pct_str) + 4);
+    let name = text_layout::truncate(skill.name, name_room);
+    let marker = if selected { "▶ " } else { "◆ " };
+    let dots_len = inner_w.saturating_sub(
+        text_layout::width(marker) + text_layout::width(&name) + 1 + text_layout::width(&pct_str),
+    );
+
+    let label_style = Style::new().fg(accent).bold();
+    let name_style = if selected {
+        label_style.reversed()
+    } else {
+        label_style
+    };
+    let mut x = text_layout::draw(buf, inner, inner.x, label_y, marker, label_style);
+    x = text_layout::draw(buf, inner, x, label_y, &name, name_style);
+    x = text_layout::draw(buf, inner, x, label_y, " ", label_style);
+    let dim = Style::new().fg(FG_DIM);
+    x = text_layout::fill(buf, inner, x, label_y, "·", dots_len, dim);
+    let pct_style = Style::new().fg(FG_PRIMARY);
+    text_layout::draw(buf, inner, x, label_y, &pct_str, pct_style);
 
     // Bar line: filled ━ with gradient + unfilled ─
-    let bar_w = inner_w as usize;
-    let filled = (bar_w * animated_pct as usize) / 100;
-    let unfilled = bar_w - filled;
+    let filled = (inner_w * animated_pct as usize) / 100;
+    let unfilled = inner_w - filled;
 
-    x = inner_x;
+    x = inner.x;
     for i in 0..filled {
-        if x < area.x + area.width {
-            // Gradient: dim → accent across the bar
-            let progress = if filled > 1 {
-                i as f64 / (filled - 1) as f64
+        // Gradient: dim → accent across the bar
+        let progress = if filled > 1 {
+            i as f64 / (filled - 1) as f64
+        } else {
+            1.0
+        };
+        let grad_color = color_lerp(FG_DIM, accent, progress);
+        x = text_layout::draw(buf, inner, x, bar_y, "━", Style::new().fg(grad_color));
+    }
+    text_layout::fill(buf, inner, x, bar_y, "─", unfilled, dim);
+}
+
+/// Horizontal bar per skill, labels on the left and the percentage inside the bar.
+fn render_skill_bars(
+    buf: &mut Buffer,
+    area: Rect,
+    skills: &[&Skill],
+    selected: usize,
+    screen_tick: u64,
+) {
+    let fill = animation::value("skills.gauge", screen_tick).clamp(0.0, 1.0);
+    let bars: Vec<Bar> = skills
+        .iter()
+        .enumerate()
+        .map(|(index, skill)| {
+            let label = if index == selected {
+                Line::from(skill.name).reversed()
             } else {
-                1.0
+                Line::from(skill.name)
             };
-            let grad_color = color_lerp(FG_DIM, accent, progress);
-            buf[(x, bar_y)].set_char('━');
-            buf[(x, bar_y)].set_style(Style::new().fg(grad_color));
-            x += 1;
-        }
-    }
-    for _ in 0..unfilled {
-        if x < area.x + area.width {
-            buf[(x, bar_y)].set_char('─');
-            buf[(x, bar_y)].set_style(Style::new().fg(FG_DIM));
-            x += 1;
-        }
+            Bar::default()
+                .label(label)
+                .value((f64::from(skill.level) * fill).round() as u64)
+                .text_value(format!("{}%", skill.level))
+                .style(Style::new().fg(skill.accent))
+                .value_style(Style::new().fg(BG_CANVAS).bg(skill.accent).bold())
+        })
+        .collect();
+    // Spread the bars out when the panel has rows to spare.
+    let count = skills.len().max(1) as u16;
+    let gap = if area.height >= count * 2 { 1 } else { 0 };
+
+    BarChart::default()
+        .direction(Direction::Horizontal)
+        .data(BarGroup::default().bars(&bars))
+        .bar_width(1)
+        .bar_gap(gap)
+        .max(100)
+        .label_style(Style::new().fg(FG_PRIMARY))
+        .render(area, buf);
+}
+
+/// Fewest skills that make a polygon on the radar chart.
+const RADAR_MIN_SKILLS: usize = 3;
+
+/// Radar chart: one spoke per skill, rings every 25%, and the skill polygon on top.
+fn render_skill_radar(
+    buf: &mut Buffer,
+    area: Rect,
+    skills: &[&Skill],
+    selected: usize,
+    screen_tick: u64,
+) {
+    if skills.len() < RADAR_MIN_SKILLS || area.width < 12 || area.height < 5 {
+        return;
     }
+    let fill = animation::value("skills.gauge", screen_tick).clamp(0.0, 1.0);
+
+    // Terminal cells are about twice as tall as wide; widen the x range to keep it round.
+    let radius = 1.0;
+    let y_range = radius * 1.35;
+    let x_range = y_range * f64::from(area.width) / (2.0 * f64::from(area.height));
+    let column = 2.0 * x_range / f64::from(area.width);
+
+    let count = skills.len();
+    let spoke = |index: usize, scale: f64| {
+        let angle =
+            std::f64::consts::FRAC_PI_2 - std::f64::consts::TAU * index as f64 / count as f64;
+        (scale * angle.cos(), scale * angle.sin())
+    };
+    let outline = |scales: &[f64], color: Color| -> Vec<CanvasLine> {
+        (0..count)
+            .map(|index| {
+                let (x1, y1) = spoke(index, scales[index]);
+                let (x2, y2) = spoke((index + 1) % count, scales[(index + 1) % count]);
+                CanvasLine::new(x1, y1, x2, y2, color)
+            })
+            .collect()
+    };
+
+    Canvas::default()
+        .background_color(BG_PANEL)
+        .marker(symbols::Marker::Braille)
+        .x_bounds([-x_range, x_range])
+        .y_bounds([-y_range, y_range])
+        .paint(|ctx| {
+            for ring in [0.25, 0.5, 0.75, 1.0] {
+                for line in outline(&vec![ring * radius; count], BORDER_DIM) {
+                    ctx.draw(&line);
+                }
+            }
+            for (index, skill) in skills.iter().enumerate() {
+                let (x, y) = spoke(index, radius);
+                let color = if index == selected {
+                    skill.accent
+                } else {
+                    BORDER_DIM
+                };
+                ctx.draw(&CanvasLine::new(0.0, 0.0, x, y, color));
+            }
+            ctx.layer();
+
+            let scales: Vec<f64> = skills
+                .iter()
+                .map(|skill| f64::from(skill.level) / 100.0 * fill * radius)
+                .collect();
+            for line in outline(&scales, ACCENT_GOLD) {
+                ctx.draw(&line);
+            }
+            ctx.layer();
+
+            // Labels just past the outer ring, anchored away from the center.
+            for (index, skill) in skills.iter().enumerate() {
+                let (x, y) = spoke(index, radius * 1.12);
+                let label = format!("{} {}", skill.name, skill.level);
+                let width = text_layout::width(&label) as f64 * column;
+                let x = if x.abs() < column {
+                    x - width / 2.0
+                } else if x < 0.0 {
+                    x - width
+                } else {
+                    x
+                };
+                let x = x.clamp(-x_range, (x_range - width).max(-x_range));
+                let mut style = Style::new().fg(skill.accent).bold();
+                if index == selected {
+                    style = style.reversed();
+                }
+                ctx.print(x, y, Span::styled(label, style));
+            }
+        })
+        .render(area, buf);
 }
 
-impl Widget for FirstScreenWidget {
+impl Widget for FirstScreenWidget<'_> {
     fn render(self, area: Rect, buf: &mut Buffer) {
         buf.set_style(area, Style::new().bg(BG_CANVAS));
 
@@ -143,16 +270,10 @@ impl Widget for FirstScreenWidget {
                 format!("Karneeshkar V · {}", self.call_sign),
                 Style::new().fg(ACCENT_GOLD).bold(),
             )),
-            Line::from(Span::styled(
-                "Software engineer crafting cloud-ready systems, AI automations, and human-centered tools.",
-                Style::new().fg(FG_PRIMARY),
-            )),
+            Line::from(Span::styled(TAGLINE, Style::new().fg(FG_PRIMARY))),
             Line::from(vec![
                 Span::styled("Email:", Style::new().fg(FG_SECONDARY)),
-                Span::styled(
-                    " karneeshkar01@gmail.com",
-                    Style::new().fg(ACCENT_TEAL).bold(),
-                ),
+                Span::styled(format!(" {}", EMAIL), Style::new().fg(ACCENT_TEAL).bold()),
             ]),
         ])
         .alignment(Alignment::Center)
@@ -180,13 +301,12 @@ impl Widget for FirstScreenWidget {
             ])
             .split(sections[1]);
 
-        // Left column (stagger: appears at tick 2)
-        if self.screen_tick >= 2 {
+        // Left column appears first, right column follows
+        if animation::started("skills.left", self.screen_tick) {
             self.render_left_column(columns[0], buf);
         }
 
-        // Right column (stagger: appears at tick 6)
-        if self.screen_tick >= 6 {
+        if animation::started("skills.right", self.screen_tick) {
             self.render_right_column(columns[2], buf);
         }
 
@@ -195,12 +315,18 @@ impl Widget for FirstScreenWidget {
             buf,
             self.page,
             self.total,
-            "n for experience & projects",
+            if self.skills.browsing {
+                "j/k pick an entry · Enter to open it · Esc back to skills"
+            } else if self.skills.links().is_empty() {
+                "j/k skill · h/l category · s sort · v view"
+            } else {
+                "j/k skill · Enter where it was used · h/l category · s sort · v view"
+            },
         );
     }
 }
 
-impl FirstScreenWidget {
+impl FirstScreenWidget<'_> {
     fn render_left_column(&self, area: Rect, buf: &mut Buffer) {
         let left_layout = Layout::default()
             .direction(Direction::Vertical)
@@ -211,45 +337,31 @@ impl FirstScreenWidget {
             ])
             .split(area);
 
-        // Expertise items with styled dividers
-        let expertise_data = [
-            (
-                "Backend Development",
-                "REST APIs · FastAPI · Axum · Node.js · PHP",
-                ACCENT_TEAL,
-                false,
-            ),
-            (
-                "AI & Automation",
-                "LLMs · RAG · Function Calling · Workflow Automation",
-                ACCENT_BLUE,
-                false,
-            ),
-            (
-                "Cloud Solutions",
-                "AWS · DigitalOcean · Azure · Docker",
-                ACCENT_VIOLET,
-                false,
-            ),
-            (
-                "Frontend Development",
-                "React · JavaScript · Responsive UI · UX Design",
-                ACCENT_GOLD,
-                true,
-            ),
-        ];
-
+        // One branch per skill category, listing its skills strongest first
+        let categories = SkillCategory::in_use();
         let mut expertise_lines: Vec<Line> = Vec::new();
-        for (title, desc, accent, is_last) in &expertise_data {
-            let connector = if *is_last { "└─" } else { "├─" };
-            let cont = if *is_last { "   " } else { "│  " };
+        for (index, category) in categories.iter().enumerate() {
+            let is_last = index + 1 == categories.len();
+            let connector = if is_last { "└─" } else { "├─" };
+            let cont = if is_last { "   " } else { "│  " };
+            let skills: Vec<&str> = SKILLS
+                .iter()
+                .filter(|skill| skill.category == *category)
+                .map(|skill| skill.name)
+                .collect();
             expertise_lines.push(Line::from(vec![
                 Span::styled(connector, Style::new().fg(FG_DIM)),
-                Span::styled(format!(" {}", title), Style::new().fg(*accent).bold()),
+                Span::styled(
+                    format!(" {}", category.label()),
+                    Style::new().fg(category.accent()).bold(),
+                ),
             ]));
             expertise_lines.push(Line::from(vec![
                 Span::styled(cont, Style::new().fg(FG_DIM)),
-                Span::styled(format!(" {}", desc), Style::new().fg(FG_PRIMARY)),
+                Span::styled(
+                    format!(" {}", skills.join(" · ")),
+                    Style::new().fg(FG_PRIMARY),
+                ),
             ]));
         }
 
@@ -267,7 +379,11 @@ impl FirstScreenWidget {
                     .style(Style::new().bg(BG_PANEL)),
             )
             .wrap(Wrap { trim: true });
-        expertise.render(left_layout[0], buf);
+        if self.skills.browsing {
+            self.render_links(left_layout[0], buf);
+        } else {
+            expertise.render(left_layout[0], buf);
+        }
 
         let about = Paragraph::new(vec![
             Line::from(Span::styled(
@@ -295,6 +411,85 @@ impl FirstScreenWidget {
         about.render(left_layout[2], buf);
     }
 
+    /// Experience entries and projects that used the selected skill, in place of the
+    /// expertise tree while browsing them.
+    fn render_links(&self, area: Rect, buf: &mut Buffer) {
+        let skill = self.skills.skill();
+        let now = Month::now();
+        let experience: Vec<(String, String, Color)> = skill
+            .experience()
+            .map(|(_, role)| {
+                let title = format!("{} · {}", role.company, role.role);
+                let months = role.period.months(now);
+                let dates = format!("{} · {}", role.period.label(), calendar::duration(months));
+                (title, dates, role.accent)
+            })
+            .collect();
+        let projects: Vec<(String, String, Color)> = skill
+            .projects()
+            .map(|(_, project)| {
+                let title = project.name.to_string();
+                (title, project.period.label(), project.accent)
+            })
+            .collect();
+
+        // Links are numbered across both groups, matching `Skills::links`.
+        let mut lines: Vec<Line> = Vec::new();
+        let mut index = 0;
+        let mut selected_row = 0;
+        for (heading, entries) in [("Experience", experience), ("Projects", projects)] {
+            if entries.is_empty() {
+                continue;
+            }
+            lines.push(Line::from(Span::styled(
+                heading,
+                Style::new().fg(FG_MUTED).italic(),
+            )));
+            for (title, detail, accent) in entries {
+                let selected = index == self.skills.link;
+                index += 1;
+                let mut title = Span::styled(title, Style::new().fg(accent).bold());
+                if selected {
+                    selected_row = lines.len();
+                    title = title.reversed();
+                }
+                lines.push(Line::from(vec![
+                    Span::styled(if selected { "▶ " } else { "◆ " }, Style::new().fg(accent)),
+                    title,
+                ]));
+                lines.push(Line::from(Span::styled(
+                    format!("  {detail}"),
+                    Style::new().fg(FG_SECONDARY),
+                )));
+            }
+        }
+
+        // Scroll just far enough to keep the selected entry's two lines in view.
+        let rows = area.height.saturating_sub(2) as usize;
+        let scroll = (selected_row + 2).saturating_sub(rows);
+
+        let years = match skill.years {
+            Some(1) => " · 1 year".to_string(),
+            Some(years) => format!(" · {years} years"),
+            None => String::new(),
+        };
+        Paragraph::new(lines)
+            .scroll((scroll as u16, 0))
+            .block(
+                Block::default()
+                    .title(Span::styled(
+                        format!("── {}{} · {}% ──", skill.name, years, skill.level),
+                        Style::new().fg(skill.accent).bold(),
+                    ))
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+                    .border_style(Style::new().fg(BORDER_ACCENT))
+                    .padding(Padding::new(1, 1, 0, 0))
+                    .style(Style::new().bg(BG_PANEL)),
+            )
+            .render(area, buf);
+    }
+
     fn render_right_column(&self, area: Rect, buf: &mut Buffer) {
         let right_layout = Layout::default()
             .direction(Direction::Vertical)
@@ -305,12 +500,37 @@ impl FirstScreenWidget {
             ])
             .split(area);
 
-        // Skill gauges with highlighted border
+        // Skills for the selected category, with highlighted border
+        let category = self.skills.category();
+        let title_style = Style::new().fg(ACCENT_GOLD).bold();
+        let mut tabs = vec![Span::styled("── Technical Skills ", title_style)];
+        for other in SkillCategory::in_use() {
+            let style = if other == category {
+                Style::new().fg(BG_CANVAS).bg(ACCENT_GOLD).bold()
+            } else {
+                Style::new().fg(FG_MUTED)
+            };
+            tabs.push(Span::styled(format!(" {} ", other.label()), style));
+        }
+        tabs.push(Span::styled(" ──", title_style));
+        let mut title = Line::from(tabs);
+        // Narrow panels name only the selected category.
+        if title.width() + 2 > area.width as usize {
+            title = Line::from(Span::styled(
+                format!("── Skills · ◂ {} ▸ ──", category.label()),
+                title_style,
+            ));
+        }
+        let order = format!(
+            " {} · {} ",
+            self.skills.sort.label(),
+            self.skills.view.label()
+        );
         let skills_block = Block::default()
-            .title(Span::styled(
-                "── Technical Skills ──",
-                Style::new().fg(ACCENT_GOLD).bold(),
-            ))
+            .title(title)
+            .title_bottom(
+                Line::from(Span::styled(order, Style::new().fg(FG_MUTED))).right_aligned(),
+            )
             .borders(Borders::ALL)
             .border_type(BorderType::Rounded)
             .border_style(Style::new().fg(BORDER_ACCENT))
@@ -323,25 +543,38 @@ impl FirstScreenWidget {
             vertical: 1,
         });
 
-        let skills = [
-            ("C++", 90u16, ACCENT_TEAL),
-            ("Python", 85, ACCENT_GOLD),
-            ("Rust", 80, ACCENT_VIOLET),
-            ("JavaScript", 75, ACCENT_BLUE),
-            ("Go", 65, ACCENT_GREEN),
-            ("Terraform", 60, ACCENT_RED),
-        ];
-
-        for (i, (name, pct, accent)) in skills.iter().enumerate() {
-            render_skill_gauge(
-                buf,
-                inner,
-                name,
-                *pct,
-                *accent,
-                (i as u16) * 2,
-                self.screen_tick,
-            );
+        let skills = self.skills.visible();
+        let selected = self.skills.selected;
+        match self.skills.view {
+            SkillView::Gauges => {
+                for (i, skill) in skills.iter().enumerate() {
+                    render_skill_gauge(
+                        buf,
+                        inner,
+                        skill,
+                        i == selected,
+                        (i as u16) * 2,
+                        self.screen_tick,
+                    );
+                }
+            }
+            SkillView::Bars => {
+                render_skill_bars(buf, inner, &skills, selected, self.screen_tick);
+            }
+            SkillView::Radar if skills.len() < RADAR_MIN_SKILLS => {
+                // Too few spokes for a polygon, so show the bars under a note instead.
+                let [note, bars] =
+                    Layout::vertical([Constraint::Length(1), Constraint::Min(0)]).areas(inner);
+                Paragraph::new(Span::styled(
+                    format!("Radar needs {RADAR_MIN_SKILLS}+ skills; showing bars."),
+                    Style::new().fg(FG_MUTED).italic(),
+                ))
+                .render(note, buf);
+                render_skill_bars(buf, bars, &skills, selected, self.screen_tick);
+            }
+            SkillView::Radar => {
+                render_skill_radar(buf, inner, &skills, selected, self.screen_tick);
+            }
         }
 
         let connect = Paragraph::new(vec![
@@ -1,80 +1,45 @@
 use ratatui::{
     layout::{Constraint, Direction, Layout, Margin},
     prelude::*,
-    style::{Color, Stylize},
+    style::Stylize,
     text::{Line, Span},
     widgets::{Block, BorderType, Borders, Paragraph, Wrap},
 };
 use tui_big_text::{BigText, PixelSize};
 
+use super::animation;
+use super::text_layout;
 use super::theme::*;
+use crate::{
+    art::{ArtFrame, ArtPiece},
+    presence::Snapshot,
+};
 
 /// Composite intro view that fills the terminal with hero text, description, and controls.
-#[derive(Copy, Clone)]
-pub struct AsciiFrame {
-    pub label: &'static str,
-    pub art: &'static [&'static str],
-    pub tagline: &'static str,
-    pub accent: Color,
-}
-
-pub const ASCII_FRAMES: [AsciiFrame; 2] = [
-    AsciiFrame {
-        label: "Neovim",
-        art: &[
-            "        ,l;             c,      ",
-            "    .:ooool'           loo:.   ",
-            "  .,oooooooo:.         looooc, ",
-            " ll:,loooooool,        looooool",
-            " llll,;ooooooooc.      looooooo",
-            " lllllc,coooooooo;     looooooo",
-            " lllllll;,loooooool'   looooooo",
-            " lllllllc .:oooooooo:. looooooo",
-            " lllllllc   'loooooool,:ooooooo",
-            " lllllllc     ;ooooooooc,cooooo",
-            " lllllllc      .coooooooo;;looo",
-            " lllllllc        ,loooooool,:ol",
-            "  'cllllc         .:oooooooo;. ",
-            "    .;llc           .loooo:.   ",
-            "       ,;             ;l;      ",
-        ],
-        tagline: "Modal editing keeps the flow alive.",
-        accent: ACCENT_TEAL,
-    },
-    AsciiFrame {
-        label: "Linux",
-        art: &[
-            "       .--.          ",
-            "      |o_o |         ",
-            "      |:_/ |         ",
-            "     //   \\ \\       ",
-            "    (|     | )       ",
-            "   /'\\_   _/`\\      ",
-            "   \\___)=(___/      ",
-            "  Powered by Linux   ",
-        ],
-        tagline: "Freedom-driven systems tinkering.",
-        accent: ACCENT_GOLD,
-    },
-];
-
-pub struct IntroScreenWidget {
-    frame: AsciiFrame,
+pub struct IntroScreenWidget<'a> {
+    art: &'a ArtPiece,
+    frame: &'a ArtFrame,
+    /// Live visitor counts, or `None` when the presence registry is unavailable.
+    visitors: Option<Snapshot>,
     page: usize,
     total: usize,
     screen_tick: u64,
     global_tick: u64,
 }
 
-pub fn intro_screen(
-    frame: AsciiFrame,
+pub fn intro_screen<'a>(
+    art: &'a ArtPiece,
+    frame: &'a ArtFrame,
+    visitors: Option<Snapshot>,
     page: usize,
     total: usize,
     screen_tick: u64,
     global_tick: u64,
-) -> IntroScreenWidget {
+) -> IntroScreenWidget<'a> {
     IntroScreenWidget {
+        art,
         frame,
+        visitors,
         page,
         total,
         screen_tick,
@@ -82,7 +47,7 @@ pub fn intro_screen(
     }
 }
 
-impl Widget for IntroScreenWidget {
+impl Widget for IntroScreenWidget<'_> {
     fn render(self, area: Rect, buf: &mut Buffer) {
         buf.set_style(area, Style::new().bg(BG_CANVAS));
 
@@ -109,12 +74,12 @@ impl Widget for IntroScreenWidget {
         self.render_status_bar(sections[0], buf);
 
         // ── Hero section ──
-        if self.screen_tick >= 2 {
+        if animation::started("intro.hero", self.screen_tick) {
             self.render_hero(sections[1], buf);
         }
 
         // ── Highlight cards ──
-        if self.screen_tick >= 8 {
+        if animation::started("intro.highlights", self.screen_tick) {
             self.render_highlights(sections[2], buf);
         }
 
@@ -129,27 +94,22 @@ impl Widget for IntroScreenWidget {
     }
 }
 
-impl IntroScreenWidget {
+impl IntroScreenWidget<'_> {
     fn render_status_bar(&self, area: Rect, buf: &mut Buffer) {
-        let progress = (self.screen_tick * 10).min(100) as usize;
+        let progress =
+            ((animation::value("intro.status", self.screen_tick) * 100.0) as usize).min(100);
         let bar_width = 10;
         let filled = (bar_width * progress) / 100;
         let unfilled = bar_width - filled;
 
-        let bar: String = format!(
-            "{}{}",
-            "█".repeat(filled),
-            "░".repeat(unfilled),
-        );
-
         let status_line = Line::from(vec![
             Span::styled("▊ ", Style::new().fg(ACCENT_TEAL)),
             Span::styled("ssh://karneeshkar.dev", Style::new().fg(FG_SECONDARY)),
             Span::styled("  ·  ", Style::new().fg(FG_DIM)),
-            Span::styled("session active", Style::new().fg(ACCENT_GREEN)),
+            Span::styled(self.visitors_label(), Style::new().fg(ACCENT_GREEN)),
             Span::styled("  ·  ", Style::new().fg(FG_DIM)),
-            Span::styled(&bar[..filled * 3], Style::new().fg(ACCENT_TEAL)), // █ is 3 bytes
-            Span::styled(&bar[filled * 3..], Style::new().fg(FG_DIM)),
+            Span::styled("█".repeat(filled), Style::new().fg(ACCENT_TEAL)),
+            Span::styled("░".repeat(unfilled), Style::new().fg(FG_DIM)),
             Span::styled(format!(" {}%", progress), Style::new().fg(FG_MUTED)),
             Span::styled(" ▊", Style::new().fg(ACCENT_TEAL)),
         ]);
@@ -160,6 +120,21 @@ impl IntroScreenWidget {
         status.render(area, buf);
     }
 
+    fn visitors_label(&self) -> String {
+        match self.visitors {
+            Some(Snapshot {
+                online,
+                total_visits,
+            }) => format!(
+                "{} {} online · {} visits",
+                online,
+                if online == 1 { "visitor" } else { "visitors" },
+                group_thousands(total_visits)
+            ),
+            None => "session active".to_string(),
+        }
+    }
+
     fn render_hero(&self, area: Rect, buf: &mut Buffer) {
         let hero_chunks = Layout::default()
             .direction(Direction::Horizontal)
@@ -168,12 +143,8 @@ impl IntroScreenWidget {
 
         buf.set_style(area, Style::new().bg(BG_HERO));
 
-        // Fade-in effect for hero text (ticks 2-6)
-        let fade_progress = if self.screen_tick < 2 {
-            0.0
-        } else {
-            ((self.screen_tick - 2) as f64 / 4.0).min(1.0)
-        };
+        // Fade-in effect for hero text
+        let fade_progress = animation::value("intro.hero.fade", self.screen_tick);
 
         let teal_faded = color_lerp(FG_DIM, ACCENT_TEAL, fade_progress);
         let blue_faded = color_lerp(FG_DIM, ACCENT_BLUE, fade_progress);
@@ -195,20 +166,24 @@ impl IntroScreenWidget {
             // Place cursor at a fixed position in the hero area
             let cursor_x = hero_chunks[0].x + 2;
             let cursor_y = hero_chunks[0].y + hero_chunks[0].height.saturating_sub(2);
-            if cursor_x < hero_chunks[0].right() && cursor_y < hero_chunks[0].bottom() {
-                buf[(cursor_x, cursor_y)].set_char('▌');
-                buf[(cursor_x, cursor_y)].set_style(Style::new().fg(ACCENT_TEAL));
-            }
+            text_layout::draw(
+                buf,
+                hero_chunks[0],
+                cursor_x,
+                cursor_y,
+                "▌",
+                Style::new().fg(ACCENT_TEAL),
+            );
         }
 
         // Right column: about + ASCII art
-        if self.screen_tick >= 5 {
+        if animation::started("intro.hero.detail", self.screen_tick) {
             self.render_hero_right(hero_chunks[1], buf);
         }
     }
 
     fn render_hero_right(&self, area: Rect, buf: &mut Buffer) {
-        let frame = self.frame;
+        let art = self.art;
 
         let about_lines = vec![
             Line::from(Span::styled(
@@ -240,7 +215,7 @@ impl IntroScreenWidget {
             .wrap(Wrap { trim: true });
 
         let ascii_panel_height = if area.height > 7 {
-            (frame.art.len() as u16 + 3).min(area.height - 7)
+            (art.height() as u16 + 3).min(area.height - 7)
         } else {
             area.height / 2
         };
@@ -256,27 +231,35 @@ impl IntroScreenWidget {
 
         about.render(hero_detail_chunks[0], buf);
 
-        let mut ascii_lines: Vec<Line> = frame
-            .art
+        // Pad short frames so the tagline does not jump while the piece animates
+        let mut ascii_lines: Vec<Line> = self
+            .frame
+            .lines
             .iter()
-            .map(|line| Line::from(Span::styled(*line, Style::new().fg(frame.accent).bold())))
+            .map(|line| {
+                Line::from(Span::styled(
+                    line.as_str(),
+                    Style::new().fg(art.accent).bold(),
+                ))
+            })
             .collect();
+        ascii_lines.resize(art.height(), Line::from(""));
         ascii_lines.push(Line::from(Span::raw("")));
         ascii_lines.push(Line::from(Span::styled(
-            frame.tagline,
+            art.tagline.as_str(),
             Style::new().fg(FG_SECONDARY).italic(),
         )));
 
         // Pulsing border on ASCII art panel
-        let pulsing_border = pulsing_accent(frame.accent, self.global_tick, 10);
+        let pulsing_border = pulsing_accent(art.accent, self.global_tick, 10);
 
         let ascii_panel = Paragraph::new(ascii_lines)
             .alignment(Alignment::Center)
             .block(
                 Block::default()
                     .title(Span::styled(
-                        frame.label,
-                        Style::new().fg(frame.accent).bold(),
+                        art.label.as_str(),
+                        Style::new().fg(art.accent).bold(),
                     ))
                     .borders(Borders::ALL)
                     .border_type(BorderType::Rounded)
@@ -304,10 +287,10 @@ impl IntroScreenWidget {
         buf.set_style(area, Style::new().bg(BG_SECTION));
 
         // Staggered card appearance
-        let card_tick_offsets = [8u64, 10, 12];
+        let cards = animation::get("intro.card");
 
         // Focus Areas (appears first)
-        if self.screen_tick >= card_tick_offsets[0] {
+        if cards.started_at(self.screen_tick, 0) {
             let focus = Paragraph::new(vec![
                 Line::from(Span::styled("◆ AI agents", Style::new().fg(ACCENT_TEAL))),
                 Line::from(Span::styled(
@@ -348,7 +331,7 @@ impl IntroScreenWidget {
         }
 
         // Toolbox (appears second)
-        if self.screen_tick >= card_tick_offsets[1] {
+        if cards.started_at(self.screen_tick, 1) {
             let toolbox_lines = vec![
                 Line::from(Span::styled(
                     "◆ Rust | Go | C++ | Python",
@@ -372,7 +355,7 @@ impl IntroScreenWidget {
                     Block::default()
                         .title(Span::styled(
                             " ◆ Toolbox ",
-                            Style::new().fg(self.frame.accent).bold(),
+                            Style::new().fg(self.art.accent).bold(),
                         ))
                         .borders(Borders::ALL)
                         .border_type(BorderType::Rounded)
@@ -384,7 +367,7 @@ impl IntroScreenWidget {
         }
 
         // Connect (appears third)
-        if self.screen_tick >= card_tick_offsets[2] {
+        if cards.started_at(self.screen_tick, 2) {
             let contact = Paragraph::new(vec![
                 Line::from(Span::styled(
                     "◆ github.com/KarneeshkarV",
@@ -414,3 +397,16 @@ impl IntroScreenWidget {
         }
     }
 }
+
+/// `1204` → `1,204`.
+fn group_thousands(value: u64) -> String {
+    let digits = value.to_string();
+    let mut grouped = String::with_capacity(digits.len() + digits.len() / 3);
+    for (index, digit) in digits.chars().enumerate() {
+        if index > 0 && (digits.len() - index).is_multiple_of(3) {
+            grouped.push(',');
+        }
+        grouped.push(digit);
+    }
+    grouped
+}
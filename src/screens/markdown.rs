@@ -0,0 +1,453 @@
+//! Markdown to styled, pre-wrapped ratatui lines, using the theme palette.
+//!
+//! Lines are wrapped here rather than by `Paragraph` so callers know the exact line count and
+//! can clamp scrolling.
+
+use pulldown_cmark::{CodeBlockKind, Event, HeadingLevel, Options, Parser, Tag, TagEnd};
+use ratatui::{
+    style::{Modifier, Style},
+    text::{Line, Span},
+};
+
+use super::text_layout::{self, width};
+use super::theme::*;
+use crate::highlight::{self, Language};
+
+/// Render `markdown` into lines no wider than `width` columns.
+pub fn render(markdown: &str, width: usize) -> Vec<Line<'static>> {
+    let options =
+        Options::ENABLE_TABLES | Options::ENABLE_STRIKETHROUGH | Options::ENABLE_TASKLISTS;
+    let mut renderer = Renderer {
+        width: width.max(8),
+        lines: Vec::new(),
+        spans: Vec::new(),
+        styles: vec![Style::new().fg(FG_PRIMARY)],
+        lists: Vec::new(),
+        bullet: None,
+        quote_depth: 0,
+        heading: None,
+        code: None,
+        links: Vec::new(),
+        table: None,
+    };
+    for event in Parser::new_ext(markdown, options) {
+        renderer.event(event);
+    }
+    renderer.flush();
+    while renderer
+        .lines
+        .last()
+        .is_some_and(|line| line.spans.is_empty())
+    {
+        renderer.lines.pop();
+    }
+    renderer.lines
+}
+
+#[derive(Default)]
+struct Table {
+    rows: Vec<Vec<Vec<Span<'static>>>>,
+    header_rows: usize,
+}
+
+struct Renderer {
+    width: usize,
+    lines: Vec<Line<'static>>,
+    /// Inline content of the block being built, not yet wrapped.
+    spans: Vec<Span<'static>>,
+    styles: Vec<Style>,
+    /// Open lists, innermost last; `Some(n)` is the next number of an ordered list.
+    lists: Vec<Option<u64>>,
+    /// Marker to print before the next line of a list item.
+    bullet: Option<String>,
+    quote_depth: usize,
+    heading: Option<HeadingLevel>,
+    /// Language and contents of the fenced code block being read.
+    code: Option<(String, String)>,
+    /// Destinations of open links.
+    links: Vec<String>,
+    table: Option<Table>,
+}
+
+impl Renderer {
+    fn style(&self) -> Style {
+        self.styles.last().copied().unwrap_or_default()
+    }
+
+    fn push_style(&mut self, style: Style) {
+        self.styles.push(self.style().patch(style));
+    }
+
+    fn pop_style(&mut self) {
+        if self.styles.len() > 1 {
+            self.styles.pop();
+        }
+    }
+
+    fn text(&mut self, text: String, style: Style) {
+        if let Some(table) = &mut self.table {
+            if let Some(cell) = table.rows.last_mut().and_then(|row| row.last_mut()) {
+                cell.push(Span::styled(text, style));
+            }
+        } else {
+            self.spans.push(Span::styled(text, style));
+        }
+    }
+
+    fn blank(&mut self) {
+        if self.lines.last().is_some_and(|line| !line.spans.is_empty()) {
+            self.lines.push(Line::default());
+        }
+    }
+
+    fn event(&mut self, event: Event) {
+        match event {
+            Event::Start(tag) => self.start(tag),
+            Event::End(tag) => self.end(tag),
+            Event::Text(text) => match &mut self.code {
+                Some((_, code)) => code.push_str(&text),
+                None => self.text(text.into_string(), self.style()),
+            },
+            Event::Code(code) => {
+                self.text(code.into_string(), self.style().fg(ACCENT_GOLD).bg(BG_CARD))
+            }
+            Event::SoftBreak => self.text(" ".to_string(), self.style()),
+            Event::HardBreak => self.flush(),
+            Event::Rule => {
+                self.flush();
+                self.lines.push(Line::from(Span::styled(
+                    "─".repeat(self.width),
+                    Style::new().fg(FG_DIM),
+                )));
+                self.blank();
+            }
+            Event::TaskListMarker(done) => {
+                let marker = if done { "[x] " } else { "[ ] " };
+                self.text(marker.to_string(), Style::new().fg(ACCENT_GREEN));
+            }
+            Event::Html(html) | Event::InlineHtml(html) => {
+                self.text(html.into_string(), Style::new().fg(FG_DIM));
+            }
+            _ => {}
+        }
+    }
+
+    fn start(&mut self, tag: Tag) {
+        match tag {
+            Tag::Heading { level, .. } => {
+                self.flush();
+                self.heading = Some(level);
+                let style = match level {
+                    HeadingLevel::H1 => Style::new().fg(ACCENT_TEAL),
+                    HeadingLevel::H2 => Style::new().fg(ACCENT_BLUE),
+                    _ => Style::new().fg(ACCENT_VIOLET),
+                };
+                self.push_style(style.add_modifier(Modifier::BOLD));
+            }
+            Tag::BlockQuote(_) => {
+                self.flush();
+                self.quote_depth += 1;
+                self.push_style(Style::new().fg(FG_SECONDARY).add_modifier(Modifier::ITALIC));
+            }
+            Tag::CodeBlock(kind) => {
+                self.flush();
+                let language = match kind {
+                    CodeBlockKind::Fenced(language) => language.into_string(),
+                    CodeBlockKind::Indented => String::new(),
+                };
+                self.code = Some((language, String::new()));
+            }
+            Tag::List(start) => {
+                self.flush();
+                self.lists.push(start);
+            }
+            Tag::Item => {
+                self.flush();
+                let depth = self.lists.len().saturating_sub(1);
+                let marker = match self.lists.last_mut() {
+                    Some(Some(number)) => {
+                        *number += 1;
+                        format!("{}. ", *number - 1)
+                    }
+                    _ => "• ".to_string(),
+                };
+                self.bullet = Some(format!("{}{marker}", "  ".repeat(depth)));
+            }
+            Tag::Emphasis => self.push_style(Style::new().add_modifier(Modifier::ITALIC)),
+            Tag::Strong => self.push_style(Style::new().add_modifier(Modifier::BOLD)),
+            Tag::Strikethrough => {
+                self.push_style(Style::new().add_modifier(Modifier::CROSSED_OUT));
+            }
+            Tag::Link { dest_url, .. } => {
+                self.links.push(dest_url.into_string());
+                self.push_style(
+                    Style::new()
+                        .fg(ACCENT_BLUE)
+                        .add_modifier(Modifier::UNDERLINED),
+                );
+            }
+            Tag::Image { .. } => {
+                self.text("[image: ".to_string(), Style::new().fg(FG_MUTED));
+            }
+            Tag::Table(_) => {
+                self.flush();
+                self.table = Some(Table::default());
+            }
+            Tag::TableHead | Tag::TableRow => {
+                if let Some(table) = &mut self.table {
+                    table.rows.push(Vec::new());
+                }
+            }
+            Tag::TableCell => {
+                if let Some(row) = self.table.as_mut().and_then(|table| table.rows.last_mut()) {
+                    row.push(Vec::new());
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn end(&mut self, tag: TagEnd) {
+        match tag {
+            TagEnd::Paragraph => {
+                self.flush();
+                if self.lists.is_empty() {
+                    self.blank();
+                }
+            }
+            TagEnd::Heading(level) => {
+                self.flush();
+                self.pop_style();
+                self.heading = None;
+                if level == HeadingLevel::H1 {
+                    self.lines.push(Line::from(Span::styled(
+                        "━".repeat(self.width),
+                        Style::new().fg(BORDER_ACCENT),
+                    )));
+                }
+                self.blank();
+            }
+            TagEnd::BlockQuote(_) => {
+                self.flush();
+                self.quote_depth = self.quote_depth.saturating_sub(1);
+                self.pop_style();
+                self.blank();
+            }
+            TagEnd::CodeBlock => {
+                if let Some((language, code)) = self.code.take() {
+                    self.code_block(&language, &code);
+                }
+                self.blank();
+            }
+            TagEnd::List(_) => {
+                self.flush();
+                self.lists.pop();
+                if self.lists.is_empty() {
+                    self.blank();
+                }
+            }
+            TagEnd::Item => self.flush(),
+            TagEnd::Emphasis | TagEnd::Strong | TagEnd::Strikethrough => self.pop_style(),
+            TagEnd::Link => {
+                self.pop_style();
+                if let Some(url) = self.links.pop() {
+                    let shown = self.spans.last().is_some_and(|span| span.content == url);
+                    if !shown {
+                        self.text(format!(" ⟨{url}⟩"), Style::new().fg(FG_DIM));
+                    }
+                }
+            }
+            TagEnd::Image => self.text("]".to_string(), Style::new().fg(FG_MUTED)),
+            TagEnd::TableHead => {
+                if let Some(table) = &mut self.table {
+                    table.header_rows = table.rows.len();
+                }
+            }
+            TagEnd::Table => {
+                if let Some(table) = self.table.take() {
+                    self.table_lines(table);
+                }
+                self.blank();
+            }
+            _ => {}
+        }
+    }
+
+    /// Prefix for the first and following lines of the current block.
+    fn prefixes(&mut self) -> (Vec<Span<'static>>, Vec<Span<'static>>) {
+        let quote = Span::styled(
+            "│ ".repeat(self.quote_depth),
+            Style::new().fg(ACCENT_VIOLET),
+        );
+        let mut first = vec![quote.clone()];
+        let mut rest = vec![quote];
+        if let Some(level) = self.heading {
+            let marker = match level {
+                HeadingLevel::H1 => "█ ",
+                HeadingLevel::H2 => "▍ ",
+                _ => "▹ ",
+            };
+            first.push(Span::styled(marker, self.style()));
+            rest.push(Span::raw(" ".repeat(width(marker))));
+        } else if !self.lists.is_empty() {
+            let depth = self.lists.len();
+            match self.bullet.take() {
+                Some(bullet) => {
+                    let indent = width(&bullet);
+                    first.push(Span::styled(bullet, Style::new().fg(ACCENT_GOLD)));
+                    rest.push(Span::raw(" ".repeat(indent)));
+                }
+                None => {
+                    // A later paragraph of the same item lines up with its text.
+                    let indent = " ".repeat(depth * 2 + 1);
+                    first.push(Span::raw(indent.clone()));
+                    rest.push(Span::raw(indent));
+                }
+            }
+        }
+        (first, rest)
+    }
+
+    /// Wrap the pending inline spans into lines.
+    fn flush(&mut self) {
+        if self.spans.is_empty() {
+            return;
+        }
+        let spans = std::mem::take(&mut self.spans);
+        let (first, rest) = self.prefixes();
+        let indent: usize = first.iter().map(|span| width(&span.content)).sum();
+        for (index, row) in wrap_spans(spans, self.width.saturating_sub(indent))
+            .into_iter()
+            .enumerate()
+        {
+            let mut line = if index == 0 {
+                first.clone()
+            } else {
+                rest.clone()
+            };
+            line.extend(row);
+            self.lines.push(Line::from(line));
+        }
+    }
+
+    fn code_block(&mut self, language: &str, code: &str) {
+        let label = if language.is_empty() {
+            "code"
+        } else {
+            language
+        };
+        self.lines.push(Line::from(vec![
+            Span::styled(
+                format!(" {label} "),
+                Style::new().fg(BG_CANVAS).bg(ACCENT_VIOLET),
+            ),
+            Span::styled(
+                " ".repeat(self.width.saturating_sub(width(label) + 2)),
+                Style::new().bg(BG_CARD),
+            ),
+        ]));
+        let code = code.trim_end_matches('\n').replace('\t', "    ");
+        for runs in highlight::highlight(&code, Language::from_name(language)) {
+            // Clip to the width, leaving room for the one-column left margin.
+            let mut room = self.width.saturating_sub(1);
+            let mut spans = vec![Span::styled(" ", Style::new().bg(BG_CARD))];
+            for (token, text) in runs {
+                let text = text_layout::clip(&text, room).to_string();
+                room -= width(&text);
+                spans.push(Span::styled(text, token_style(token).bg(BG_CARD)));
+            }
+            spans.push(Span::styled(" ".repeat(room), Style::new().bg(BG_CARD)));
+            self.lines.push(Line::from(spans));
+        }
+    }
+
+    fn table_lines(&mut self, table: Table) {
+        let columns = table.rows.iter().map(Vec::len).max().unwrap_or(0);
+        if columns == 0 {
+            return;
+        }
+        let cell_width = |cell: &Vec<Span>| cell.iter().map(|span| width(&span.content)).sum();
+        let mut widths: Vec<usize> = (0..columns)
+            .map(|column| {
+                table
+                    .rows
+                    .iter()
+                    .filter_map(|row| row.get(column))
+                    .map(cell_width)
+                    .max()
+                    .unwrap_or(0)
+            })
+            .collect();
+        // Each column is padded by a space on both sides, with a separator between columns.
+        let available = self.width.saturating_sub(columns * 3 - 1);
+        if widths.iter().sum::<usize>() > available {
+            let fair = (available / columns).max(3);
+            for width in &mut widths {
+                *width = (*width).min(fair);
+            }
+        }
+
+        for (index, row) in table.rows.iter().enumerate() {
+            let header = index < table.header_rows;
+            let mut spans = Vec::new();
+            for (column, width) in widths.iter().enumerate() {
+                if column > 0 {
+                    spans.push(Span::styled("│", Style::new().fg(BORDER_DIM)));
+                }
+                let text: String = row
+                    .get(column)
+                    .map(|cell| cell.iter().map(|span| span.content.as_ref()).collect())
+                    .unwrap_or_default();
+                let style = if header {
+                    Style::new().fg(ACCENT_TEAL).add_modifier(Modifier::BOLD)
+                } else {
+                    Style::new().fg(FG_PRIMARY)
+                };
+                spans.push(Span::styled(
+                    format!(" {} ", text_layout::pad(&text, *width)),
+                    style,
+                ));
+            }
+            self.lines.push(Line::from(spans));
+            if header && index + 1 == table.header_rows {
+                let rule: Vec<String> = widths.iter().map(|width| "─".repeat(width + 2)).collect();
+                self.lines.push(Line::from(Span::styled(
+                    rule.join("┼"),
+                    Style::new().fg(BORDER_DIM),
+                )));
+            }
+        }
+    }
+}
+
+/// Greedy word wrap of styled spans; words longer than a line are split.
+fn wrap_spans(spans: Vec<Span<'static>>, columns: usize) -> Vec<Vec<Span<'static>>> {
+    let columns = columns.max(1);
+    let mut rows: Vec<Vec<Span<'static>>> = vec![Vec::new()];
+    let mut used = 0;
+    for span in spans {
+        let style = span.style;
+        for word in span.content.split_inclusive(' ') {
+            if used > 0 && used + width(word.trim_end()) > columns {
+                rows.push(Vec::new());
+                used = 0;
+            }
+            let mut word = if used == 0 { word.trim_start() } else { word }.to_string();
+            if width(word.trim_end()) > columns {
+                let mut pieces = text_layout::chunk(&word, columns);
+                word = pieces.pop().unwrap_or_default();
+                for piece in pieces {
+                    if let Some(row) = rows.last_mut() {
+                        row.push(Span::styled(piece, style));
+                    }
+                    rows.push(Vec::new());
+                }
+            }
+            used += width(&word);
+            if let Some(row) = rows.last_mut() {
+                row.push(Span::styled(word, style));
+            }
+        }
+    }
+    rows
+}
@@ -1,5 +1,19 @@
+pub mod activity_screen;
+pub mod admin_screen;
+pub mod animation;
+pub mod arcade_screen;
+pub mod blog_screen;
+pub mod busy_screen;
+pub mod code_view;
+pub mod debug_overlay;
 pub mod first_screen;
 pub mod intro_screen;
+pub mod markdown;
 pub mod second_screen;
+pub mod session_notice;
+pub mod shell_screen;
+pub mod text_layout;
 pub mod theme;
 pub mod third_screen;
+pub mod timeline_view;
+pub mod transition;
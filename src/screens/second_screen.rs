@@ -6,25 +6,38 @@ use ratatui::{
     widgets::{Block, BorderType, Borders, Padding, Paragraph, Wrap},
 };
 
+use super::animation;
+use super::code_view::code_view;
 use super::theme::*;
+use super::timeline_view::timeline_view;
+use crate::calendar::{self, Month};
+use crate::content::{EXPERIENCE, PROJECTS, experience_months, recent_first};
+use crate::projects::Projects;
+use crate::timeline::Timeline;
 
-pub struct SecondScreenWidget {
+pub struct SecondScreenWidget<'a> {
     call_sign: String,
+    projects: &'a Projects,
+    timeline: &'a Timeline,
     page: usize,
     total: usize,
     screen_tick: u64,
     global_tick: u64,
 }
 
-pub fn second_screen(
+pub fn second_screen<'a>(
     call_sign: &str,
+    projects: &'a Projects,
+    timeline: &'a Timeline,
     page: usize,
     total: usize,
     screen_tick: u64,
     global_tick: u64,
-) -> SecondScreenWidget {
+) -> SecondScreenWidget<'a> {
     SecondScreenWidget {
         call_sign: call_sign.to_string(),
+        projects,
+        timeline,
         page,
         total,
         screen_tick,
@@ -32,7 +45,7 @@ pub fn second_screen(
     }
 }
 
-impl Widget for SecondScreenWidget {
+impl Widget for SecondScreenWidget<'_> {
     fn render(self, area: Rect, buf: &mut Buffer) {
         buf.set_style(area, Style::new().bg(BG_CANVAS));
 
@@ -54,6 +67,8 @@ impl Widget for SecondScreenWidget {
             ])
             .split(content);
 
+        let now = Month::now();
+        let latest = &EXPERIENCE[recent_first(EXPERIENCE, |entry| entry.period)[0]];
         let header = Paragraph::new(vec![
             Line::from(Span::styled(
                 format!("Experience & Projects · {}", self.call_sign),
@@ -64,7 +79,13 @@ impl Widget for SecondScreenWidget {
                 Style::new().fg(FG_PRIMARY),
             )),
             Line::from(Span::styled(
-                "Latest role: Tech Lead @ 2Cents Capital (July 2025 - Present).",
+                format!(
+                    "Latest role: {} @ {} ({}) · {:.1} years of experience.",
+                    latest.role,
+                    latest.company,
+                    latest.period.label(),
+                    experience_months(now) as f64 / 12.0
+                ),
                 Style::new().fg(FG_SECONDARY),
             )),
         ])
@@ -84,6 +105,18 @@ impl Widget for SecondScreenWidget {
         header.render(sections[0], buf);
 
         buf.set_style(sections[1], Style::new().bg(BG_SECTION));
+        if self.projects.viewing {
+            code_view(self.projects).render(sections[1], buf);
+            render_footer(
+                sections[2],
+                buf,
+                self.page,
+                self.total,
+                "j/k or PgUp/PgDn to scroll · Tab next file · Esc back to projects",
+            );
+            return;
+        }
+
         let main_split = Layout::default()
             .direction(Direction::Horizontal)
             .constraints([
@@ -93,7 +126,11 @@ impl Widget for SecondScreenWidget {
             ])
             .split(sections[1]);
 
-        self.render_experience(main_split[0], buf);
+        if self.timeline.open {
+            timeline_view(self.timeline, self.projects.experience).render(main_split[0], buf);
+        } else {
+            self.render_experience(main_split[0], buf);
+        }
         self.render_projects(main_split[2], buf);
 
         render_footer(
@@ -101,122 +138,128 @@ impl Widget for SecondScreenWidget {
             buf,
             self.page,
             self.total,
-            "Email karneeshkar01@gmail.com for resume and collaborations.",
+            match (self.timeline.open, self.projects.snippet().is_some()) {
+                (true, true) => {
+                    "+/- zoom · t back to the list · j/k pick a project · Enter to view its code"
+                }
+                (true, false) => "+/- zoom · t back to the list · j/k pick a project",
+                (false, true) => "t for a timeline · j/k pick a project · Enter to view its code",
+                (false, false) => "t for a timeline · j/k pick a project",
+            },
         );
     }
 }
 
-impl SecondScreenWidget {
+impl SecondScreenWidget<'_> {
     fn render_experience(&self, area: Rect, buf: &mut Buffer) {
-        let experience_data: Vec<(&str, &str, &str, &str, &str, Color)> = vec![
-            (
-                "2Cents Capital",
-                "Tech Lead",
-                "July 2025 - Present",
-                "Led and scaled cross-functional Mobile and AI teams (10+ engineers) to deliver high-impact features with 95% on-time release rate.",
-                "Published a fully responsive investment application on both iOS App Store and Google Play, achieving <0.5s cold-start time.",
-                ACCENT_GOLD,
-            ),
-            (
-                "Visteon",
-                "Embedded Systems Developer",
-                "Jan 2025 - Present",
-                "Optimized real-time automobile dashboards, cutting processing latency by 25%.",
-                "Engineered fault-tolerant microcontroller code and 95% accurate diagnostics.",
-                ACCENT_GOLD,
-            ),
-            (
-                "UpWork",
-                "Freelance Developer",
-                "Nov 2024 - Jan 2025",
-                "Delivered AI-powered RAG legal solutions with 92% accuracy and IoT firmware boosts.",
-                "Completed 5+ engagements maintaining a 4.9/5 satisfaction score.",
-                ACCENT_TEAL,
-            ),
-            (
-                "Procter & Gamble",
-                "IoT Developer & Automation Intern",
-                "Dec 2024 - Jan 2025",
-                "Rolled out IIoT monitoring across two lines capturing 200K+ daily data points.",
-                "Automated quality checks trimming manual inspection time by 15%.",
-                ACCENT_BLUE,
-            ),
-            (
-                "Intellect Design Arena Ltd",
-                "Software Intern",
-                "Sept 2024 - Dec 2024",
-                "Built PHP-based financial sites with 10% faster loads and managed AWS uptime to 99.9%.",
-                "Crafted analytics dashboards for real-time engagement insights.",
-                ACCENT_VIOLET,
-            ),
-            (
-                "TYNATECH Ingenious Pvt Ltd",
-                "IoT & LoRaWAN Developer",
-                "May 2024 - Jun 2024",
-                "Integrated 10+ LoRaWAN devices via REST APIs and MQTT visualizations handling 20 msg/s.",
-                "Boosted UART-LoRaWAN throughput by 10% through buffer tuning.",
-                ACCENT_GOLD,
-            ),
-            (
-                "Hindustan Aeronautics Limited",
-                "R&D Intern",
-                "July 2024 - Aug 2024",
-                "Optimized autopilot algorithms reducing computational overhead by 8%.",
-                "Delivered C++ tooling validating 40+ control system scenarios.",
-                ACCENT_TEAL,
-            ),
-        ];
-
-        let entry_count = experience_data.len();
+        let entry_count = EXPERIENCE.len();
+        let order = recent_first(EXPERIENCE, |entry| entry.period);
+        let now = Month::now();
 
-        // Staggered reveal: each entry appears 2 ticks apart
-        let visible = ((self.screen_tick / 2) as usize).min(entry_count);
+        // Staggered reveal, one entry after another
+        let entries = animation::get("experience.entry");
+        let visible = (0..entry_count)
+            .take_while(|&i| entries.started_at(self.screen_tick, i))
+            .count();
 
-        let mut exp_lines: Vec<Line> = Vec::new();
+        let mut entries: Vec<Vec<Line>> = Vec::new();
 
-        for (i, (company, role, date, desc1, desc2, accent)) in
-            experience_data[..visible].iter().enumerate()
-        {
+        for (i, &index) in order[..visible].iter().enumerate() {
+            let entry = &EXPERIENCE[index];
+            let mut exp_lines: Vec<Line> = Vec::new();
+            let highlighted = self.projects.experience == Some(index);
+            let accent = &entry.accent;
             let is_last = i == entry_count - 1;
-            // Thick timeline connectors
-            let marker = if is_last { "┗━━" } else { "┣━━" };
+            // Thick timeline connectors, an arrow for the role linked from the Skills screen
+            let marker = match (highlighted, is_last) {
+                (true, true) => "┗━▶",
+                (true, false) => "┣━▶",
+                (false, true) => "┗━━",
+                (false, false) => "┣━━",
+            };
             let cont = if is_last { "    " } else { "┃   " };
 
-            // Current role gets pulsing accent
-            let timeline_color = if i == 0 {
+            // Ongoing roles get a pulsing accent
+            let timeline_color = if entry.period.end.is_none() {
                 pulsing_accent(*accent, self.global_tick, 8)
             } else {
                 *accent
             };
 
             // Company + role line
+            let company_style = if highlighted {
+                Style::new().fg(*accent).bold().reversed()
+            } else {
+                Style::new().fg(*accent).bold()
+            };
             exp_lines.push(Line::from(vec![
                 Span::styled(marker, Style::new().fg(timeline_color)),
-                Span::styled(format!(" {}", company), Style::new().fg(*accent).bold()),
-                Span::styled(format!(" · {}", role), Style::new().fg(FG_PRIMARY).bold()),
+                Span::raw(" "),
+                Span::styled(entry.company, company_style),
+                Span::styled(
+                    format!(" · {}", entry.role),
+                    Style::new().fg(FG_PRIMARY).bold(),
+                ),
             ]));
-            // Date line
+            // Date line with the role's length
             exp_lines.push(Line::from(vec![
                 Span::styled(cont, Style::new().fg(FG_DIM)),
-                Span::styled(format!(" {}", date), Style::new().fg(ACCENT_BLUE)),
+                Span::styled(
+                    format!(" {}", entry.period.label()),
+                    Style::new().fg(ACCENT_BLUE),
+                ),
+                Span::styled(
+                    format!(" · {}", calendar::duration(entry.period.months(now))),
+                    Style::new().fg(FG_MUTED),
+                ),
             ]));
             // Description lines
             exp_lines.push(Line::from(vec![
                 Span::styled(cont, Style::new().fg(FG_DIM)),
-                Span::styled(format!(" {}", desc1), Style::new().fg(FG_PRIMARY)),
+                Span::styled(
+                    format!(" {}", entry.highlights[0]),
+                    Style::new().fg(FG_PRIMARY),
+                ),
             ]));
             exp_lines.push(Line::from(vec![
                 Span::styled(cont, Style::new().fg(FG_DIM)),
-                Span::styled(format!(" {}", desc2), Style::new().fg(FG_SECONDARY)),
+                Span::styled(
+                    format!(" {}", entry.highlights[1]),
+                    Style::new().fg(FG_SECONDARY),
+                ),
             ]));
             // Separator between entries
             if !is_last && i < visible.saturating_sub(1) {
                 exp_lines.push(Line::from(Span::styled("┃", Style::new().fg(FG_DIM))));
             }
+            entries.push(exp_lines);
         }
 
+        // Skip leading entries until the highlighted one fits, estimating wrapped heights.
+        let last = entries.len().saturating_sub(1);
+        let highlighted = self.projects.experience;
+        let target = highlighted
+            .and_then(|index| order.iter().position(|&at| at == index))
+            .unwrap_or(0)
+            .min(last);
+        let text_width = area.width.saturating_sub(4).max(1) as usize;
+        let text_height = area.height.saturating_sub(2) as usize;
+        let height = |lines: &Vec<Line>| -> usize {
+            lines
+                .iter()
+                .map(|line| line.width().div_ceil(text_width).max(1))
+                .sum()
+        };
+        let mut first = 0;
+        while first < target
+            && entries[first..=target].iter().map(height).sum::<usize>() > text_height
+        {
+            first += 1;
+        }
+        let exp_lines: Vec<Line> = entries.into_iter().skip(first).flatten().collect();
+
         // Pulsing border for the experience panel to draw attention
-        let panel_border = if self.screen_tick < 20 {
+        let panel_border = if animation::get("experience.attention").running(self.screen_tick) {
             pulsing_accent(BORDER_ACCENT, self.global_tick, 12)
         } else {
             BORDER_DIM
@@ -249,57 +292,39 @@ impl SecondScreenWidget {
             ])
             .split(area);
 
-        let project_data: Vec<(&str, &str, &str, &str, Color)> = vec![
-            (
-                "Fine-Tuning LLaMA 3.1 8B for Code Reasoning",
-                "Apr 2025",
-                "Applied GRPO reinforcement learning with Gemma3 rewards to elevate code reasoning; released model & dataset.",
-                "LLaMA 3.1 8B · Gemma3 · Python · Hugging Face · DigitalOcean",
-                ACCENT_GOLD,
-            ),
-            (
-                "Multipurpose VR Gloves with Gesture Recognition",
-                "Sept 2024",
-                "Captured 15+ gestures with 92% accuracy and sub-20ms wireless latency for immersive control.",
-                "Unity · C# · Python · ESP32 · Flex Sensors · IMU",
-                ACCENT_TEAL,
-            ),
-            (
-                "Instagram Automation Bot",
-                "Dec 2024",
-                "Handled 1000+ daily interactions with 99.5% uptime, using LLM sentiment analysis at 88% accuracy.",
-                "Python · Meta Graph API · FastAPI · Redis · GPT Models · AWS",
-                ACCENT_VIOLET,
-            ),
-            (
-                "Smart Rhesus Macaque Deterrent System",
-                "Mar 2024",
-                "Deployed edge vision alerts with 94% detection accuracy, cutting campus intrusions by 90%.",
-                "Raspberry Pi · Jetson Nano · PyTorch · Computer Vision · Ultrasonic Emitters",
-                ACCENT_BLUE,
-            ),
-            (
-                "Wearable Jump Height Measurement Device",
-                "Mar 2024",
-                "Achieved ±1 cm accuracy with Kalman-filtered motion data and actionable training analytics.",
-                "ESP32 · MPU6050 · Flask · SQLite",
-                ACCENT_GOLD,
-            ),
-        ];
-
-        let mut proj_lines: Vec<Line> = Vec::new();
-        let proj_count = project_data.len();
+        let proj_count = PROJECTS.len();
+        let order = recent_first(PROJECTS, |project| project.period);
+        let mut entries: Vec<Vec<Line>> = Vec::new();
 
-        for (i, (name, date, desc, stack, accent)) in project_data.iter().enumerate() {
-            proj_lines.push(Line::from(vec![
-                Span::styled("◆ ", Style::new().fg(*accent)),
-                Span::styled(*name, Style::new().fg(*accent).bold()),
-                Span::styled(format!("  · {}", date), Style::new().fg(FG_SECONDARY)),
-            ]));
-            proj_lines.push(Line::from(Span::styled(*desc, Style::new().fg(FG_PRIMARY))));
+        for (i, &index) in order.iter().enumerate() {
+            let project = &PROJECTS[index];
+            let mut proj_lines: Vec<Line> = Vec::new();
+            let selected = index == self.projects.selected;
+            let mut title = vec![
+                Span::styled(
+                    if selected { "▶ " } else { "◆ " },
+                    Style::new().fg(project.accent),
+                ),
+                Span::styled(project.name, Style::new().fg(project.accent).bold()),
+                Span::styled(
+                    format!("  · {}", project.period.label()),
+                    Style::new().fg(FG_SECONDARY),
+                ),
+            ];
+            if selected {
+                title[1] = title[1].clone().reversed();
+                if !self.projects.snippets().is_empty() {
+                    title.push(Span::styled("  ⏎ code", Style::new().fg(FG_MUTED)));
+                }
+            }
+            proj_lines.push(Line::from(title));
+            proj_lines.push(Line::from(Span::styled(
+                project.summary,
+                Style::new().fg(FG_PRIMARY),
+            )));
             proj_lines.push(Line::from(vec![
                 Span::styled("╰ ", Style::new().fg(FG_DIM)),
-                Span::styled(*stack, Style::new().fg(FG_MUTED).italic()),
+                Span::styled(project.stack, Style::new().fg(FG_MUTED).italic()),
             ]));
             if i < proj_count - 1 {
                 // Dotted separator pattern
@@ -308,7 +333,29 @@ impl SecondScreenWidget {
                     Style::new().fg(FG_DIM),
                 )));
             }
+            entries.push(proj_lines);
+        }
+
+        // Skip leading projects until the selected one fits, estimating wrapped heights.
+        let text_width = right_column[0].width.saturating_sub(4).max(1) as usize;
+        let text_height = right_column[0].height.saturating_sub(2) as usize;
+        let height = |lines: &Vec<Line>| -> usize {
+            lines
+                .iter()
+                .map(|line| line.width().div_ceil(text_width).max(1))
+                .sum()
+        };
+        let target = order
+            .iter()
+            .position(|&index| index == self.projects.selected)
+            .unwrap_or(0);
+        let mut first = 0;
+        while first < target
+            && entries[first..=target].iter().map(height).sum::<usize>() > text_height
+        {
+            first += 1;
         }
+        let proj_lines: Vec<Line> = entries.into_iter().skip(first).flatten().collect();
 
         let projects = Paragraph::new(proj_lines)
             .block(
@@ -0,0 +1,103 @@
+use std::time::Duration;
+
+use ratatui::{
+    layout::{Constraint, Flex, Layout},
+    prelude::*,
+    style::Stylize,
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Clear, Padding, Paragraph},
+};
+
+use super::theme::*;
+use crate::session_timer::Limit;
+
+/// What the session-limit overlay is telling the visitor.
+#[derive(Debug, Clone, Copy)]
+pub enum Notice {
+    /// `Limit` will end the session after the given time.
+    Warning(Limit, Duration),
+    /// The session is over; shown briefly before disconnecting.
+    Goodbye(Limit),
+}
+
+/// Small centered card drawn over the current screen.
+pub struct SessionNoticeWidget {
+    notice: Notice,
+    global_tick: u64,
+}
+
+pub fn session_notice(notice: Notice, global_tick: u64) -> SessionNoticeWidget {
+    SessionNoticeWidget {
+        notice,
+        global_tick,
+    }
+}
+
+impl Widget for SessionNoticeWidget {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let [row] = Layout::vertical([Constraint::Length(8)])
+            .flex(Flex::Center)
+            .areas(area);
+        let [card] = Layout::horizontal([Constraint::Max(52)])
+            .flex(Flex::Center)
+            .areas(row);
+
+        let (title, accent, lines) = match self.notice {
+            Notice::Warning(limit, left) => {
+                let headline = match limit {
+                    Limit::Idle => "Still there? Disconnecting for inactivity in",
+                    Limit::MaxDuration => "This session reaches its time limit in",
+                };
+                (
+                    "── Heads Up ──",
+                    pulsing_accent(ACCENT_GOLD, self.global_tick, 5),
+                    vec![
+                        Line::from(Span::styled(headline, Style::new().fg(FG_PRIMARY))),
+                        Line::from(Span::styled(
+                            format!("{}s", left.as_secs()),
+                            Style::new().fg(ACCENT_GOLD).bold(),
+                        )),
+                        Line::default(),
+                        Line::from(Span::styled(
+                            "Press any key to continue.",
+                            Style::new().fg(FG_MUTED).italic(),
+                        )),
+                    ],
+                )
+            }
+            Notice::Goodbye(limit) => {
+                let reason = match limit {
+                    Limit::Idle => "Disconnected after a while without input.",
+                    Limit::MaxDuration => "This session has reached its time limit.",
+                };
+                (
+                    "── Goodbye ──",
+                    ACCENT_TEAL,
+                    vec![
+                        Line::from(Span::styled(reason, Style::new().fg(FG_PRIMARY))),
+                        Line::default(),
+                        Line::from(Span::styled(
+                            "Thanks for stopping by. Reconnect any time.",
+                            Style::new().fg(ACCENT_TEAL),
+                        )),
+                    ],
+                )
+            }
+        };
+
+        Clear.render(card, buf);
+        Paragraph::new(lines)
+            .alignment(Alignment::Center)
+            .block(
+                Block::default()
+                    .title(Span::styled(title, Style::new().fg(accent).bold()))
+                    .title_alignment(Alignment::Center)
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+                    .border_style(Style::new().fg(accent))
+                    .padding(Padding::uniform(1))
+                    .style(Style::new().bg(BG_HERO)),
+            )
+            .render(card, buf);
+    }
+}
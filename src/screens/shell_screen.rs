@@ -0,0 +1,136 @@
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Margin},
+    prelude::*,
+    style::Stylize,
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Padding, Paragraph},
+};
+
+use super::text_layout;
+use super::theme::*;
+use crate::shell::{PROMPT, Shell, Tone};
+
+/// Full-screen pseudo-terminal for the portfolio shell.
+pub struct ShellScreenWidget<'a> {
+    shell: &'a Shell,
+    page: usize,
+    total: usize,
+    global_tick: u64,
+}
+
+pub fn shell_screen(
+    shell: &Shell,
+    page: usize,
+    total: usize,
+    global_tick: u64,
+) -> ShellScreenWidget<'_> {
+    ShellScreenWidget {
+        shell,
+        page,
+        total,
+        global_tick,
+    }
+}
+
+impl Widget for ShellScreenWidget<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        buf.set_style(area, Style::new().bg(BG_CANVAS));
+
+        let content = area.inner(Margin {
+            horizontal: 2,
+            vertical: 1,
+        });
+
+        if content.width < 30 || content.height < 10 {
+            return;
+        }
+
+        let sections = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(5), Constraint::Length(5)])
+            .split(content);
+
+        let block = Block::default()
+            .title(Span::styled(
+                "── visitor@karneeshkar.dev ──",
+                Style::new().fg(ACCENT_GREEN).bold(),
+            ))
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::new().fg(BORDER_ACCENT))
+            .padding(Padding::horizontal(1))
+            .style(Style::new().bg(BG_HERO));
+        let inner = block.inner(sections[0]);
+        block.render(sections[0], buf);
+
+        let width = inner.width as usize;
+        let mut rows: Vec<Line> = Vec::new();
+        for line in &self.shell.scrollback {
+            let style = match line.tone {
+                Tone::Command => Style::new().fg(FG_SECONDARY),
+                Tone::Text => Style::new().fg(FG_PRIMARY),
+                Tone::Heading => Style::new().fg(ACCENT_TEAL).bold(),
+                Tone::Muted => Style::new().fg(FG_MUTED).italic(),
+                Tone::Error => Style::new().fg(ACCENT_RED),
+            };
+            rows.extend(
+                text_layout::chunk(&line.text, width)
+                    .into_iter()
+                    .map(|row| Line::from(Span::styled(row, style))),
+            );
+        }
+        rows.extend(self.prompt_rows(width));
+
+        let visible = inner.height as usize;
+        let skip = rows.len().saturating_sub(visible);
+        Paragraph::new(rows.split_off(skip)).render(inner, buf);
+
+        render_footer(
+            sections[1],
+            buf,
+            self.page,
+            self.total,
+            "Type `help` for commands · Esc or `exit` to leave",
+        );
+    }
+}
+
+impl ShellScreenWidget<'_> {
+    /// The prompt and input, wrapped, with a blinking block cursor.
+    fn prompt_rows(&self, width: usize) -> Vec<Line<'static>> {
+        let cursor_on = (self.global_tick / 3).is_multiple_of(2);
+        let prompt_len = PROMPT.chars().count();
+        let mut cells: Vec<(char, Style)> = PROMPT
+            .chars()
+            .map(|c| (c, Style::new().fg(ACCENT_GREEN).bold()))
+            .chain(
+                self.shell
+                    .input
+                    .chars()
+                    .map(|c| (c, Style::new().fg(FG_PRIMARY))),
+            )
+            .collect();
+        cells.push((' ', Style::new()));
+        let cursor = prompt_len + self.shell.cursor;
+        if cursor_on {
+            cells[cursor].1 = Style::new().fg(BG_HERO).bg(ACCENT_TEAL);
+        }
+
+        // Break rows by display width so wide characters never overflow the panel.
+        let mut rows: Vec<Vec<Span<'static>>> = vec![Vec::new()];
+        let mut used = 0;
+        for (c, style) in cells {
+            let mut encoded = [0; 4];
+            let cell_width = text_layout::width(c.encode_utf8(&mut encoded));
+            if used > 0 && used + cell_width > width {
+                rows.push(Vec::new());
+                used = 0;
+            }
+            used += cell_width;
+            if let Some(row) = rows.last_mut() {
+                row.push(Span::styled(c.to_string(), style));
+            }
+        }
+        rows.into_iter().map(Line::from).collect()
+    }
+}
@@ -0,0 +1,229 @@
+//! Width-aware text measurement and clipped drawing for widgets that write straight into the
+//! [`Buffer`].
+//!
+//! Everything here counts terminal columns rather than bytes or `char`s, keeps grapheme
+//! clusters (emoji sequences, combining marks) whole, and clamps to the area it is handed, so
+//! no terminal size can make a renderer slice mid-character or index outside the buffer.
+
+use std::borrow::Cow;
+
+use ratatui::{buffer::Buffer, layout::Position, layout::Rect, style::Style};
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+pub const ELLIPSIS: &str = "…";
+
+/// Columns `text` occupies in a terminal.
+pub fn width(text: &str) -> usize {
+    text.width()
+}
+
+/// The longest prefix of `text` that fits in `columns`, cut without an ellipsis.
+pub fn clip(text: &str, columns: usize) -> &str {
+    let mut used = 0;
+    let mut end = 0;
+    for grapheme in text.graphemes(true) {
+        used += grapheme.width();
+        if used > columns {
+            break;
+        }
+        end += grapheme.len();
+    }
+    &text[..end]
+}
+
+/// `text` cut to at most `columns`, ending in an ellipsis when anything was dropped.
+pub fn truncate(text: &str, columns: usize) -> Cow<'_, str> {
+    if width(text) <= columns {
+        return Cow::Borrowed(text);
+    }
+    if columns == 0 {
+        return Cow::Borrowed("");
+    }
+    Cow::Owned(format!("{}{ELLIPSIS}", clip(text, columns - 1)))
+}
+
+/// `text` truncated or padded with trailing spaces to exactly `columns`.
+pub fn pad(text: &str, columns: usize) -> String {
+    let text = truncate(text, columns);
+    let fill = columns.saturating_sub(width(&text));
+    format!("{text}{}", " ".repeat(fill))
+}
+
+/// Split `text` into rows at most `columns` wide, breaking between graphemes.
+///
+/// Always returns at least one row, so an empty line still takes up space.
+pub fn chunk(text: &str, columns: usize) -> Vec<String> {
+    let columns = columns.max(1);
+    let mut rows = vec![String::new()];
+    let mut used = 0;
+    for grapheme in text.graphemes(true) {
+        let grapheme_width = grapheme.width();
+        if used > 0 && used + grapheme_width > columns {
+            rows.push(String::new());
+            used = 0;
+        }
+        used += grapheme_width;
+        if let Some(row) = rows.last_mut() {
+            row.push_str(grapheme);
+        }
+    }
+    rows
+}
+
+/// Draw `text` starting at `(x, y)`, clipped to `area` with an ellipsis.
+///
+/// Returns the column after the last cell drawn, or `x` when the position is outside `area`.
+pub fn draw(buf: &mut Buffer, area: Rect, x: u16, y: u16, text: &str, style: Style) -> u16 {
+    let area = area.intersection(buf.area);
+    if !area.contains(Position::new(x, y)) {
+        return x;
+    }
+    let room = usize::from(area.right() - x);
+    let (end, _) = buf.set_stringn(x, y, truncate(text, room), room, style);
+    end
+}
+
+/// Draw `count` copies of `symbol` starting at `(x, y)`, clipped to `area`.
+///
+/// Returns the column after the last cell drawn, like [`draw`].
+pub fn fill(
+    buf: &mut Buffer,
+    area: Rect,
+    x: u16,
+    y: u16,
+    symbol: &str,
+    count: usize,
+    style: Style,
+) -> u16 {
+    let area = area.intersection(buf.area);
+    if !area.contains(Position::new(x, y)) || count == 0 {
+        return x;
+    }
+    let room = usize::from(area.right() - x);
+    let count = count.min(room / width(symbol).max(1));
+    let (end, _) = buf.set_stringn(x, y, symbol.repeat(count), room, style);
+    end
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLES: [&str; 7] = [
+        "",
+        "plain ascii",
+        "日本語のテキスト",
+        "a日b本c",
+        "👩‍👩‍👧 family",
+        "cafe\u{301} au lait",
+        "🎉🎉🎉",
+    ];
+
+    #[test]
+    fn clip_fits_and_is_a_prefix() {
+        for text in SAMPLES {
+            for columns in 0..=20 {
+                let clipped = clip(text, columns);
+                assert!(width(clipped) <= columns, "{text:?} at {columns}");
+                assert!(text.starts_with(clipped));
+            }
+        }
+    }
+
+    #[test]
+    fn clip_never_splits_a_wide_glyph() {
+        assert_eq!(clip("日本", 3), "日");
+        assert_eq!(clip("日本", 1), "");
+        assert_eq!(clip("👩‍👩‍👧x", 1), "");
+        assert_eq!(clip("cafe\u{301}", 4), "cafe\u{301}");
+    }
+
+    #[test]
+    fn truncate_fits_and_marks_cuts() {
+        for text in SAMPLES {
+            for columns in 0..=20 {
+                let truncated = truncate(text, columns);
+                assert!(width(&truncated) <= columns, "{text:?} at {columns}");
+                if width(text) <= columns {
+                    assert_eq!(truncated, text);
+                } else if columns > 0 {
+                    assert!(truncated.ends_with(ELLIPSIS));
+                }
+            }
+        }
+        assert_eq!(truncate("日本", 3), "日…");
+        assert_eq!(truncate("日本", 4), "日本");
+        assert_eq!(truncate("日本語", 2), "…");
+        assert_eq!(truncate("abc", 0), "");
+    }
+
+    #[test]
+    fn pad_is_exactly_the_requested_width() {
+        for text in SAMPLES {
+            for columns in 0..=20 {
+                assert_eq!(width(&pad(text, columns)), columns, "{text:?} at {columns}");
+            }
+        }
+    }
+
+    #[test]
+    fn chunk_keeps_every_grapheme_in_order() {
+        for text in SAMPLES {
+            for columns in 0..=20 {
+                let rows = chunk(text, columns);
+                assert!(!rows.is_empty());
+                assert_eq!(rows.concat(), text);
+                for row in &rows {
+                    // A grapheme wider than the row still gets a row of its own.
+                    assert!(
+                        width(row) <= columns.max(1) || row.graphemes(true).count() == 1,
+                        "{text:?} at {columns}: {row:?}"
+                    );
+                }
+            }
+        }
+        assert_eq!(chunk("日本語", 3), ["日", "本", "語"]);
+        assert_eq!(chunk("", 0), [""]);
+    }
+
+    #[test]
+    fn draw_and_fill_stay_inside_any_area() {
+        let sizes = [(0, 0), (1, 1), (2, 1), (3, 2), (10, 3)];
+        for (buf_width, buf_height) in sizes {
+            let mut buf = Buffer::empty(Rect::new(0, 0, buf_width, buf_height));
+            for (area_width, area_height) in sizes {
+                // Areas may start past the buffer or overhang it.
+                for origin in [0, 1, 5] {
+                    let area = Rect::new(origin, origin, area_width, area_height);
+                    for (x, y) in [(0, 0), (1, 0), (origin, origin), (9, 2), (u16::MAX, 0)] {
+                        for text in SAMPLES {
+                            let end = draw(&mut buf, area, x, y, text, Style::new());
+                            assert!(end == x || end <= area.right());
+                        }
+                        for symbol in ["─", "日", "👩‍👩‍👧"] {
+                            let end = fill(&mut buf, area, x, y, symbol, 7, Style::new());
+                            assert!(end == x || end <= area.right());
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn draw_does_not_split_a_wide_glyph_at_the_edge() {
+        let area = Rect::new(0, 0, 3, 1);
+        let mut buf = Buffer::empty(area);
+        let end = draw(&mut buf, area, 0, 0, "日本", Style::new());
+        assert_eq!(end, 3);
+        assert_eq!(buf[(0, 0)].symbol(), "日");
+        assert_eq!(buf[(2, 0)].symbol(), ELLIPSIS);
+
+        let area = Rect::new(0, 0, 1, 1);
+        let mut buf = Buffer::empty(area);
+        draw(&mut buf, area, 0, 0, "日本", Style::new());
+        assert_eq!(buf[(0, 0)].symbol(), ELLIPSIS);
+        assert_eq!(fill(&mut buf, area, 0, 0, "日", 3, Style::new()), 0);
+    }
+}
@@ -5,6 +5,8 @@ use ratatui::{
     widgets::{Block, Borders, Paragraph},
 };
 
+use crate::highlight::Token;
+
 pub const BG_CANVAS: Color = Color::Rgb(13, 17, 23); // Darker, github-dimmed like
 pub const BG_HERO: Color = Color::Rgb(22, 27, 34);
 pub const BG_SECTION: Color = Color::Rgb(18, 22, 29);
@@ -27,6 +29,27 @@ pub const FG_DIM: Color = Color::Rgb(75, 85, 99);
 pub const BORDER_DIM: Color = Color::Rgb(55, 65, 81);
 pub const BORDER_ACCENT: Color = Color::Rgb(75, 85, 110);
 
+/// Resolve an accent by name (`teal`, `gold`, ...) or as a `#rrggbb` hex triplet.
+pub fn parse_accent(value: &str) -> Option<Color> {
+    let value = value.trim();
+    if let Some(hex) = value.strip_prefix('#') {
+        if hex.len() != 6 || !hex.is_ascii() {
+            return None;
+        }
+        let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).ok();
+        return Some(Color::Rgb(channel(0)?, channel(2)?, channel(4)?));
+    }
+    match value.to_ascii_lowercase().as_str() {
+        "teal" => Some(ACCENT_TEAL),
+        "blue" => Some(ACCENT_BLUE),
+        "violet" => Some(ACCENT_VIOLET),
+        "gold" => Some(ACCENT_GOLD),
+        "red" => Some(ACCENT_RED),
+        "green" => Some(ACCENT_GREEN),
+        _ => None,
+    }
+}
+
 /// Linearly interpolate between two RGB colors. `t` is clamped to [0.0, 1.0].
 /// Returns `from` unchanged if either color is not RGB.
 pub fn color_lerp(from: Color, to: Color, t: f64) -> Color {
@@ -68,24 +91,38 @@ pub fn pulsing_accent(base: Color, tick: u64, period: u8) -> Color {
 }
 
 /// Screen names for the tab bar footer.
-const SCREEN_NAMES: [&str; 4] = ["Intro", "Skills", "Experience", "Telemetry"];
+const SCREEN_NAMES: [&str; 7] = [
+    "Intro",
+    "Skills",
+    "Experience",
+    "Telemetry",
+    "Arcade",
+    "Blog",
+    "Activity",
+];
 
 /// Render a standardized footer with nav keys, page indicator, and screen tab bar.
 pub fn render_footer(area: Rect, buf: &mut Buffer, page: usize, total: usize, hint: &str) {
     buf.set_style(area, Style::new().bg(BG_FOOTER));
 
-    // Build tab bar line
+    // Build tab bar line, dropping the outer padding when it would not fit
+    let roomy: usize = SCREEN_NAMES.iter().map(|name| name.len() + 5).sum();
+    let pad = if roomy <= area.width as usize {
+        " "
+    } else {
+        ""
+    };
     let mut tab_spans: Vec<Span> = Vec::new();
     for (i, name) in SCREEN_NAMES.iter().enumerate() {
         let screen_num = i + 1;
         if screen_num == page {
             tab_spans.push(Span::styled(
-                format!(" [{}] ", name),
+                format!("{pad}[{}]{pad}", name),
                 Style::new().fg(ACCENT_TEAL).bold(),
             ));
         } else {
             tab_spans.push(Span::styled(
-                format!("  {}  ", name),
+                format!("{pad} {} {pad}", name),
                 Style::new().fg(FG_DIM),
             ));
         }
@@ -113,49 +150,23 @@ pub fn render_footer(area: Rect, buf: &mut Buffer, page: usize, total: usize, hi
         Style::new().fg(FG_DIM),
     )]);
 
+    let mut key_spans = vec![
+        Span::styled("p", Style::new().fg(ACCENT_GOLD).bold()),
+        Span::styled(" prev", Style::new().fg(FG_PRIMARY)),
+        Span::styled("  ·  ", Style::new().fg(FG_DIM)),
+        Span::styled("n", Style::new().fg(ACCENT_GOLD).bold()),
+        Span::styled(" next", Style::new().fg(FG_PRIMARY)),
+        Span::styled("  ·  ", Style::new().fg(FG_DIM)),
+        Span::styled("q", Style::new().fg(ACCENT_RED).bold()),
+        Span::styled(" quit", Style::new().fg(FG_PRIMARY)),
+        Span::styled("   ", Style::new().fg(FG_DIM)),
+    ];
+    key_spans.extend(dot_spans);
+
     let footer = Paragraph::new(vec![
         separator,
         Line::from(tab_spans),
-        Line::from(vec![
-            Span::styled("p", Style::new().fg(ACCENT_GOLD).bold()),
-            Span::styled(" prev", Style::new().fg(FG_PRIMARY)),
-            Span::styled("  ·  ", Style::new().fg(FG_DIM)),
-            Span::styled("n", Style::new().fg(ACCENT_GOLD).bold()),
-            Span::styled(" next", Style::new().fg(FG_PRIMARY)),
-            Span::styled("  ·  ", Style::new().fg(FG_DIM)),
-            Span::styled("q", Style::new().fg(ACCENT_RED).bold()),
-            Span::styled(" quit", Style::new().fg(FG_PRIMARY)),
-            Span::styled("   ", Style::new().fg(FG_DIM)),
-            dot_spans[0].clone(),
-            dot_spans
-                .get(1)
-                .cloned()
-                .unwrap_or(Span::raw("")),
-            dot_spans
-                .get(2)
-                .cloned()
-                .unwrap_or(Span::raw("")),
-            dot_spans
-                .get(3)
-                .cloned()
-                .unwrap_or(Span::raw("")),
-            dot_spans
-                .get(4)
-                .cloned()
-                .unwrap_or(Span::raw("")),
-            dot_spans
-                .get(5)
-                .cloned()
-                .unwrap_or(Span::raw("")),
-            dot_spans
-                .get(6)
-                .cloned()
-                .unwrap_or(Span::raw("")),
-            dot_spans
-                .get(7)
-                .cloned()
-                .unwrap_or(Span::raw("")),
-        ]),
+        Line::from(key_spans),
         Line::from(Span::styled(hint, Style::new().fg(FG_MUTED).italic())),
     ])
     .alignment(Alignment::Center)
@@ -166,3 +177,18 @@ pub fn render_footer(area: Rect, buf: &mut Buffer, page: usize, total: usize, hi
     );
     footer.render(area, buf);
 }
+
+/// Palette for syntax-highlighted code.
+pub fn token_style(token: Token) -> Style {
+    match token {
+        Token::Plain => Style::new().fg(FG_PRIMARY),
+        Token::Keyword => Style::new().fg(ACCENT_VIOLET).bold(),
+        Token::Type => Style::new().fg(ACCENT_TEAL),
+        Token::Function => Style::new().fg(ACCENT_BLUE),
+        Token::String => Style::new().fg(ACCENT_GREEN),
+        Token::Number => Style::new().fg(ACCENT_GOLD),
+        Token::Comment => Style::new().fg(FG_MUTED).italic(),
+        Token::Attribute => Style::new().fg(ACCENT_RED),
+        Token::Punctuation => Style::new().fg(FG_SECONDARY),
+    }
+}
@@ -2,14 +2,40 @@ use ratatui::{
     layout::{Constraint, Direction, Layout, Margin},
     prelude::*,
     style::Stylize,
+    symbols::Marker,
     text::{Line, Span},
-    widgets::{Block, BorderType, Borders, Paragraph, Sparkline, Wrap},
+    widgets::{
+        Axis, Block, BorderType, Borders, Chart, Dataset, GraphType, Padding, Paragraph, Row,
+        Sparkline, Table, Wrap,
+    },
 };
 
+use super::animation;
+use super::text_layout;
 use super::theme::*;
+use crate::{
+    calendar,
+    inspector::Inspector,
+    telemetry::{
+        AlertKind, Feed, History, Level, Monitor, Series, SeriesAlert, SeriesSpec, Summary,
+    },
+};
 
-pub struct SparkWidget {
-    data: [Vec<u64>; 3],
+/// Smallest sparkline panel worth drawing.
+const MIN_PANEL_WIDTH: u16 = 24;
+const MIN_PANEL_HEIGHT: u16 = 5;
+/// Preferred panel width-to-height ratio, in cells.
+const PANEL_ASPECT: f64 = 3.0;
+/// Width of the alert history beside the sparklines, shown when the grid keeps most of the row.
+const HISTORY_WIDTH: u16 = 36;
+/// Width of the statistics table beside the full chart.
+const STATS_WIDTH: u16 = 28;
+
+pub struct SparkWidget<'a> {
+    series: &'a [SeriesSpec],
+    history: &'a History,
+    alerts: &'a Monitor,
+    inspector: &'a Inspector,
     page: usize,
     total: usize,
     screen_tick: u64,
@@ -25,35 +51,16 @@ struct SeriesStats {
 }
 
 impl SeriesStats {
-    fn from_series(series: &[u64]) -> Self {
-        if series.is_empty() {
-            return Self {
-                last: 0,
-                max: 0,
-                avg: 0.0,
-                delta: 0,
-            };
-        }
-
-        let mut max = series[0];
-        let mut sum = 0u64;
-        for &value in series {
-            if value > max {
-                max = value;
-            }
-            sum += value;
-        }
-
-        let last = *series.last().unwrap();
-        let first = series[0];
-        let avg = sum as f64 / series.len() as f64;
-        let delta = last as i64 - first as i64;
+    /// Read off the statistics `series` keeps as readings arrive, without scanning it.
+    fn from_series(series: &Series) -> Self {
+        let last = series.last().unwrap_or(0);
+        let first = series.first().unwrap_or(0);
 
         Self {
             last,
-            max,
-            avg,
-            delta,
+            max: series.max().unwrap_or(0),
+            avg: series.mean().unwrap_or(0.0),
+            delta: last as i64 - first as i64,
         }
     }
 
@@ -88,7 +95,46 @@ impl SeriesStats {
     }
 }
 
-impl ratatui::widgets::Widget for SparkWidget {
+/// Columns and rows for `count` panels in `area`: the layout whose panels come closest to
+/// [`PANEL_ASPECT`], with a small penalty for each empty slot in the last row.
+///
+/// When even one panel per column cannot fit every row, the grid is as large as will fit and
+/// the remaining series are left out.
+fn grid(count: usize, area: Rect) -> (usize, usize) {
+    let max_columns = usize::from(area.width / MIN_PANEL_WIDTH).clamp(1, count.max(1));
+    let max_rows = usize::from(area.height / MIN_PANEL_HEIGHT).max(1);
+    let score = |&(columns, rows): &(usize, usize)| {
+        let width = f64::from(area.width) / columns as f64;
+        let height = f64::from(area.height) / rows as f64;
+        let empty = (columns * rows).saturating_sub(count);
+        (width / height / PANEL_ASPECT).ln().abs() + 0.3 * empty as f64
+    };
+    (1..=max_columns)
+        .map(|columns| (columns, count.div_ceil(columns)))
+        .filter(|&(_, rows)| rows <= max_rows)
+        .min_by(|a, b| score(a).total_cmp(&score(b)))
+        .unwrap_or((max_columns, max_rows))
+}
+
+/// Border and banner color for a series' alert state.
+fn alert_color(state: &SeriesAlert) -> Option<Color> {
+    match state.level {
+        Level::Critical => Some(ACCENT_RED),
+        Level::Warning => Some(ACCENT_GOLD),
+        Level::Normal if state.anomalous => Some(ACCENT_VIOLET),
+        Level::Normal => None,
+    }
+}
+
+/// Short description of what a series is alerting on, e.g. `critical` or `anomaly z+3.4`.
+fn alert_label(state: &SeriesAlert) -> String {
+    match (state.level, state.z) {
+        (Level::Normal, Some(z)) => format!("anomaly z{z:+.1}"),
+        (level, _) => level.label().to_string(),
+    }
+}
+
+impl ratatui::widgets::Widget for SparkWidget<'_> {
     fn render(self, area: Rect, buf: &mut Buffer) {
         buf.set_style(area, Style::new().bg(BG_CANVAS));
 
@@ -119,12 +165,10 @@ impl ratatui::widgets::Widget for SparkWidget {
         buf.set_style(header_chunks[1], Style::new().bg(BG_SECTION));
         buf.set_style(sections[1], Style::new().bg(BG_SECTION));
 
-        let data = self.data;
+        let data = &self.history.series;
 
-        let series_stats: Vec<SeriesStats> = data
-            .iter()
-            .map(|series| SeriesStats::from_series(series))
-            .collect();
+        let series_stats: Vec<SeriesStats> = data.iter().map(SeriesStats::from_series).collect();
+        let series_count = self.series.len().min(data.len());
 
         let mut total_samples = 0usize;
         let mut latest_sum = 0u64;
@@ -133,21 +177,17 @@ impl ratatui::widgets::Widget for SparkWidget {
         let mut max_value = u64::MIN;
         let mut has_value = false;
 
-        for series in &data {
+        for series in data {
             total_samples += series.len();
             if let Some(last) = series.last() {
-                latest_sum += *last;
+                latest_sum += last;
                 latest_count += 1;
             }
 
-            for &value in series {
+            if let (Some(min), Some(max)) = (series.min(), series.max()) {
                 has_value = true;
-                if value < min_value {
-                    min_value = value;
-                }
-                if value > max_value {
-                    max_value = value;
-                }
+                min_value = min_value.min(min);
+                max_value = max_value.max(max);
             }
         }
 
@@ -175,31 +215,17 @@ impl ratatui::widgets::Widget for SparkWidget {
                 ),
             ]),
             Line::from(Span::styled(
-                "Live signal metrics refreshing every 200ms across three service clusters.",
+                format!("Live signal metrics refreshing every 200ms across {series_count} series."),
                 Style::new().fg(FG_PRIMARY),
             )),
         ])
         .alignment(Alignment::Center)
-        .block(
-            Block::default()
-                .title(Span::styled(
-                    "── Systems Pulse ──",
-                    Style::new().fg(ACCENT_TEAL).bold(),
-                ))
-                .borders(Borders::ALL)
-                .border_type(BorderType::Rounded)
-                .border_style(Style::new().fg(BORDER_ACCENT))
-                .style(Style::new().bg(BG_HERO)),
-        )
+        .block(self.header_block())
         .wrap(Wrap { trim: true });
         header.render(header_chunks[0], buf);
 
         // Metric cards with animated count-up
-        let reveal_factor = if self.screen_tick < 8 {
-            self.screen_tick as f64 / 8.0
-        } else {
-            1.0
-        };
+        let reveal_factor = animation::value("telemetry.count_up", self.screen_tick);
 
         let displayed_samples = (total_samples as f64 * reveal_factor) as usize;
         let displayed_composite = composite_signal * reveal_factor;
@@ -260,105 +286,487 @@ impl ratatui::widgets::Widget for SparkWidget {
             card.render(metrics_columns[*col_idx], buf);
         }
 
-        // Sparkline columns
-        let spark_area = sections[1];
-        let spark_columns = Layout::default()
-            .direction(Direction::Horizontal)
-            .constraints([
-                Constraint::Percentage(33),
-                Constraint::Length(1),
-                Constraint::Percentage(33),
-                Constraint::Length(1),
-                Constraint::Percentage(33),
-            ])
-            .split(spark_area);
+        if self.inspector.open && series_count > 0 {
+            self.render_chart(sections[1], buf);
+            render_footer(
+                sections[2],
+                buf,
+                self.page,
+                self.total,
+                "space pause · ←/→ scrub (shift ×10) · tab next series · esc back to grid",
+            );
+            return;
+        }
 
-        let series_meta = [
-            ("Signal Alpha", ACCENT_TEAL),
-            ("Signal Beta", ACCENT_VIOLET),
-            ("Signal Gamma", ACCENT_GOLD),
-        ];
+        // Alert history to the right when the sparklines can spare the room
+        let mut grid_area = sections[1];
+        if grid_area.width >= HISTORY_WIDTH * 3 {
+            let split = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([
+                    Constraint::Fill(1),
+                    Constraint::Length(1), // spacer
+                    Constraint::Length(HISTORY_WIDTH),
+                ])
+                .split(grid_area);
+            grid_area = split[0];
+            self.render_history(split[2], buf);
+        }
 
-        let spark_indices = [0usize, 2, 4];
-        for (idx, col_idx) in spark_indices.iter().enumerate() {
-            let (label, accent) = series_meta[idx];
-            let stats = series_stats[idx];
-            let series = &data[idx];
-
-            let spark_col_area = spark_columns[*col_idx];
-
-            // Shade background: fill interior with ░ for "graph paper" effect
-            let inner_area = spark_col_area.inner(Margin {
-                horizontal: 1,
-                vertical: 1,
-            });
-            let shade_color = Color::Rgb(15, 20, 28);
-            for y in inner_area.y..inner_area.bottom() {
-                for x in inner_area.x..inner_area.right() {
-                    buf[(x, y)].set_char('░');
-                    buf[(x, y)].set_style(Style::new().fg(shade_color).bg(BG_PANEL));
+        // Sparkline panels, as many columns and rows as suit the terminal
+        let (columns, rows) = grid(series_count, grid_area);
+        let shown = series_count.min(columns * rows);
+        let row_areas = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(vec![Constraint::Ratio(1, rows as u32); rows])
+            .split(grid_area);
+        for (row, row_area) in row_areas.iter().enumerate() {
+            // A short last row stretches its panels across the full width.
+            let first = row * columns;
+            let in_row = shown.saturating_sub(first).min(columns);
+            let mut constraints = Vec::new();
+            for panel in 0..in_row {
+                if panel > 0 {
+                    constraints.push(Constraint::Length(1)); // spacer
                 }
+                constraints.push(Constraint::Fill(1));
             }
+            let panel_areas = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints(constraints)
+                .split(*row_area);
+            for panel in 0..in_row {
+                let idx = first + panel;
+                self.render_series(idx, series_stats[idx], panel_areas[panel * 2], buf);
+            }
+        }
 
-            let block = Block::default()
-                .title(Line::from(vec![
-                    Span::styled(label, Style::new().fg(accent).bold()),
-                    Span::raw("  ·  "),
-                    Span::styled("Telemetry", Style::new().fg(FG_SECONDARY)),
-                ]))
-                .title_bottom(Line::from(vec![
+        let hint = if shown < series_count {
+            format!(
+                "{} more series need a larger terminal · data resets per launch.",
+                series_count - shown
+            )
+        } else {
+            "←/→ select a series · enter opens its chart · data resets per launch.".to_string()
+        };
+        render_footer(sections[2], buf, self.page, self.total, &hint);
+    }
+}
+
+impl SparkWidget<'_> {
+    /// Alert state of series `idx`, normal if the monitor has not seen it yet.
+    fn state(&self, idx: usize) -> SeriesAlert {
+        self.alerts.states.get(idx).copied().unwrap_or_default()
+    }
+
+    /// The Systems Pulse frame, colored by the worst active alert and carrying a banner that
+    /// names every alerting series.
+    fn header_block(&self) -> Block<'static> {
+        let block = Block::default()
+            .title(Span::styled(
+                "── Systems Pulse ──",
+                Style::new().fg(ACCENT_TEAL).bold(),
+            ))
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::new().fg(BORDER_ACCENT))
+            .style(Style::new().bg(BG_HERO));
+
+        let active: Vec<(usize, &SeriesAlert)> = self
+            .alerts
+            .active()
+            .filter(|&(idx, _)| idx < self.series.len())
+            .collect();
+        let Some(worst) = active
+            .iter()
+            .map(|(_, state)| **state)
+            .max_by_key(|state| (state.level, state.anomalous))
+            .and_then(|state| alert_color(&state))
+        else {
+            return block;
+        };
+        let worst = pulsing_accent(worst, self.global_tick, 10);
+        let mut banner = vec![Span::styled(
+            format!(
+                " ⚠ {} ALERT{} ",
+                active.len(),
+                if active.len() == 1 { "" } else { "S" }
+            ),
+            Style::new().fg(BG_CANVAS).bg(worst).bold(),
+        )];
+        for (idx, state) in active {
+            let spec = &self.series[idx];
+            let last = self
+                .history
+                .series
+                .get(idx)
+                .and_then(|series| series.last());
+            banner.push(Span::styled(
+                format!(" {} ", spec.name),
+                Style::new().fg(FG_PRIMARY).bold(),
+            ));
+            banner.push(Span::styled(
+                format!("{} {}{} ", alert_label(state), last.unwrap_or(0), spec.unit),
+                Style::new().fg(alert_color(state).unwrap_or(FG_SECONDARY)),
+            ));
+        }
+        block
+            .title_bottom(Line::from(banner).centered())
+            .border_style(Style::new().fg(worst))
+    }
+
+    /// When each series crossed its thresholds or turned anomalous, newest first.
+    fn render_history(&self, area: Rect, buf: &mut Buffer) {
+        let block = Block::default()
+            .title(Span::styled(
+                "── Alert History ──",
+                Style::new().fg(ACCENT_RED).bold(),
+            ))
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::new().fg(BORDER_DIM))
+            .style(Style::new().bg(BG_PANEL));
+        let inner = block.inner(area);
+        block.render(area, buf);
+
+        if self.alerts.history.is_empty() {
+            let thresholds = self
+                .series
+                .iter()
+                .any(|spec| spec.warning.is_some() || spec.critical.is_some());
+            let message = if thresholds {
+                "No alerts yet."
+            } else {
+                "No thresholds set; watching for anomalies."
+            };
+            Paragraph::new(Span::styled(message, Style::new().fg(FG_MUTED).italic()))
+                .wrap(Wrap { trim: true })
+                .render(inner, buf);
+            return;
+        }
+
+        let lines: Vec<Line> = self
+            .alerts
+            .history
+            .iter()
+            .take(inner.height as usize)
+            .filter_map(|alert| {
+                let spec = self.series.get(alert.series)?;
+                let (symbol, label, color) = match alert.kind {
+                    AlertKind::Level(Level::Normal) => ("▼", "ok".to_string(), ACCENT_GREEN),
+                    AlertKind::Level(Level::Warning) => ("▲", "warn".to_string(), ACCENT_GOLD),
+                    AlertKind::Level(Level::Critical) => ("▲", "crit".to_string(), ACCENT_RED),
+                    AlertKind::Anomaly(z) => ("◆", format!("z{z:+.1}"), ACCENT_VIOLET),
+                };
+                Some(Line::from(vec![
                     Span::styled(
-                        format!(" now {:>3} ", stats.last),
-                        Style::new().fg(BG_CANVAS).bg(accent).bold(),
+                        format!("{} ", calendar::time_of_day(alert.at)),
+                        Style::new().fg(FG_MUTED),
                     ),
-                    Span::styled(" │ ", Style::new().fg(FG_DIM)),
-                    Span::styled("avg", Style::new().fg(FG_MUTED)),
-                    Span::styled(format!(" {:.1}", stats.avg), Style::new().fg(FG_PRIMARY)),
-                    Span::styled(" │ ", Style::new().fg(FG_DIM)),
-                    Span::styled("trend", Style::new().fg(FG_MUTED)),
+                    Span::styled(format!("{symbol} "), Style::new().fg(color)),
                     Span::styled(
-                        format!(
-                            " {}{}",
-                            stats.trend_symbol(self.global_tick),
-                            stats.trend_magnitude()
-                        ),
-                        Style::new().fg(accent).bold(),
+                        text_layout::pad(&spec.name, 10),
+                        Style::new().fg(spec.accent),
+                    ),
+                    Span::styled(
+                        format!(" {label:<5} {}{}", alert.value, spec.unit),
+                        Style::new().fg(color),
                     ),
                 ]))
+            })
+            .collect();
+        Paragraph::new(lines).render(inner, buf);
+    }
+
+    /// The selected series as a line chart over time, with its thresholds, the scrub cursor
+    /// and a table of statistics.
+    fn render_chart(&self, area: Rect, buf: &mut Buffer) {
+        let idx = self.inspector.selected.min(self.series.len() - 1);
+        let spec = &self.series[idx];
+        let values = self
+            .history
+            .series
+            .get(idx)
+            .map_or(&[][..], Series::as_slice);
+        let times = self.history.times.as_slice();
+        let last = values.len().saturating_sub(1);
+        let cursor = self.inspector.cursor.unwrap_or(last).min(last);
+
+        let (chart_area, stats_area) = if area.width >= STATS_WIDTH * 3 {
+            let split = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([
+                    Constraint::Fill(1),
+                    Constraint::Length(1), // spacer
+                    Constraint::Length(STATS_WIDTH),
+                ])
+                .split(area);
+            (split[0], Some(split[2]))
+        } else {
+            (area, None)
+        };
+
+        // Headroom above the highest reading or threshold, whichever is higher
+        let peak = values
+            .iter()
+            .copied()
+            .chain(spec.warning)
+            .chain(spec.critical)
+            .max()
+            .unwrap_or(0);
+        let top = (peak.max(1) as f64 * 1.1).ceil();
+        let right = last.max(1) as f64;
+        let points: Vec<(f64, f64)> = values
+            .iter()
+            .enumerate()
+            .map(|(x, &value)| (x as f64, value as f64))
+            .collect();
+        let limit_line = |limit: u64| [(0.0, limit as f64), (right, limit as f64)];
+        let warning = spec.warning.map(limit_line);
+        let critical = spec.critical.map(limit_line);
+        let cursor_line = self
+            .inspector
+            .cursor
+            .map(|_| [(cursor as f64, 0.0), (cursor as f64, top)]);
+
+        let line = |data, color| {
+            Dataset::default()
+                .marker(Marker::Braille)
+                .graph_type(GraphType::Line)
+                .style(Style::new().fg(color))
+                .data(data)
+        };
+        let mut datasets = Vec::new();
+        if let Some(limit) = &warning {
+            datasets.push(line(limit, ACCENT_GOLD));
+        }
+        if let Some(limit) = &critical {
+            datasets.push(line(limit, ACCENT_RED));
+        }
+        datasets.push(line(&points, spec.accent));
+        if let Some(cursor) = &cursor_line {
+            datasets.push(line(cursor, FG_PRIMARY));
+        }
+
+        let time = |at: usize| {
+            times
+                .get(at)
+                .map_or_else(String::new, |&at| calendar::time_of_day(at))
+        };
+        let status = if self.inspector.frozen.is_some() {
+            Span::styled(
+                " ❚❚ PAUSED ",
+                Style::new().fg(BG_CANVAS).bg(ACCENT_GOLD).bold(),
+            )
+        } else {
+            Span::styled(
+                " ● LIVE ",
+                Style::new().fg(BG_CANVAS).bg(ACCENT_GREEN).bold(),
+            )
+        };
+        let value = values.get(cursor).copied().unwrap_or(0);
+        let block = Block::default()
+            .title(Line::from(vec![
+                Span::styled(
+                    format!("── {} ", spec.name),
+                    Style::new().fg(spec.accent).bold(),
+                ),
+                Span::styled(
+                    format!("· {} ── ", spec.source.label()),
+                    Style::new().fg(FG_SECONDARY),
+                ),
+                status,
+            ]))
+            .title_bottom(Line::from(vec![
+                Span::styled(" ◆ ", Style::new().fg(FG_PRIMARY)),
+                Span::styled(time(cursor), Style::new().fg(FG_SECONDARY)),
+                Span::styled(
+                    format!("  {value}{} ", spec.unit),
+                    Style::new().fg(spec.accent).bold(),
+                ),
+            ]))
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::new().fg(alert_color(&self.state(idx)).unwrap_or(BORDER_ACCENT)))
+            .style(Style::new().bg(BG_PANEL));
+
+        let axis_label = |value: f64| format!("{value:.0}{}", spec.unit);
+        let chart = Chart::new(datasets)
+            .block(block)
+            .x_axis(
+                Axis::default()
+                    .title(Span::styled("UTC", Style::new().fg(FG_MUTED)))
+                    .bounds([0.0, right])
+                    .labels([time(0), time(last / 2), time(last)])
+                    .style(Style::new().fg(FG_DIM))
+                    .labels_alignment(Alignment::Right),
+            )
+            .y_axis(
+                Axis::default()
+                    .bounds([0.0, top])
+                    .labels([axis_label(0.0), axis_label(top / 2.0), axis_label(top)])
+                    .style(Style::new().fg(FG_DIM)),
+            )
+            .style(Style::new().fg(FG_SECONDARY));
+        chart.render(chart_area, buf);
+
+        if let Some(stats_area) = stats_area {
+            self.render_stats(idx, values, stats_area, buf);
+        }
+    }
+
+    fn render_stats(&self, idx: usize, values: &[u64], area: Rect, buf: &mut Buffer) {
+        let spec = &self.series[idx];
+        let unit = spec.unit.as_str();
+        let row = |label: &'static str, value: String, color: Color| {
+            Row::new(vec![
+                Span::styled(label, Style::new().fg(FG_MUTED)),
+                Span::styled(value, Style::new().fg(color).bold()),
+            ])
+        };
+        let mut rows = Vec::new();
+        if let Some(summary) = Summary::of(values) {
+            rows.extend([
+                row("min", format!("{}{unit}", summary.min), FG_PRIMARY),
+                row("max", format!("{}{unit}", summary.max), FG_PRIMARY),
+                row("mean", format!("{:.1}{unit}", summary.mean), FG_PRIMARY),
+                row("stddev", format!("{:.1}", summary.stddev), FG_PRIMARY),
+                row("p50", format!("{}{unit}", summary.p50), spec.accent),
+                row("p95", format!("{}{unit}", summary.p95), spec.accent),
+                row("p99", format!("{}{unit}", summary.p99), spec.accent),
+            ]);
+        }
+        rows.push(row("samples", values.len().to_string(), FG_SECONDARY));
+        let limit =
+            |limit: Option<u64>| limit.map_or("—".to_string(), |limit| format!("{limit}{unit}"));
+        rows.push(row("warning", limit(spec.warning), ACCENT_GOLD));
+        rows.push(row("critical", limit(spec.critical), ACCENT_RED));
+        let state = self.state(idx);
+        rows.push(row(
+            "state",
+            if state.is_active() {
+                alert_label(&state)
+            } else {
+                Level::Normal.label().to_string()
+            },
+            alert_color(&state).unwrap_or(ACCENT_GREEN),
+        ));
+
+        let table = Table::new(rows, [Constraint::Length(9), Constraint::Fill(1)]).block(
+            Block::default()
+                .title(Span::styled(
+                    "── Statistics ──",
+                    Style::new().fg(spec.accent).bold(),
+                ))
                 .borders(Borders::ALL)
                 .border_type(BorderType::Rounded)
-                .border_style(Style::new().fg(pulsing_accent(BORDER_DIM, self.global_tick, 15)))
-                .style(Style::new().bg(BG_PANEL));
+                .border_style(Style::new().fg(BORDER_DIM))
+                .style(Style::new().bg(BG_PANEL))
+                .padding(Padding::horizontal(1)),
+        );
+        Widget::render(table, area, buf);
+    }
 
-            let sparkline = Sparkline::default()
-                .block(block)
-                .data(series)
-                .max(stats.max.max(1))
-                .style(Style::new().fg(accent));
+    fn render_series(&self, idx: usize, stats: SeriesStats, area: Rect, buf: &mut Buffer) {
+        let spec = &self.series[idx];
+        let accent = spec.accent;
+        let series = &self.history.series[idx];
+        let selected = idx == self.inspector.selected;
 
-            sparkline.render(spark_col_area, buf);
+        // Shade background: fill interior with ░ for "graph paper" effect
+        let inner_area = area.inner(Margin {
+            horizontal: 1,
+            vertical: 1,
+        });
+        let shade_color = Color::Rgb(15, 20, 28);
+        for y in inner_area.y..inner_area.bottom() {
+            text_layout::fill(
+                buf,
+                inner_area,
+                inner_area.x,
+                y,
+                "░",
+                inner_area.width as usize,
+                Style::new().fg(shade_color).bg(BG_PANEL),
+            );
         }
 
-        render_footer(
-            sections[2],
-            buf,
-            self.page,
-            self.total,
-            "Signals refresh every 200ms · data resets per launch.",
-        );
+        let state = self.state(idx);
+        let mut title = vec![
+            Span::styled(if selected { "▸ " } else { "" }, Style::new().fg(accent)),
+            Span::styled(spec.name.as_str(), Style::new().fg(accent).bold()),
+            Span::raw("  ·  "),
+            Span::styled(spec.source.label(), Style::new().fg(FG_SECONDARY)),
+        ];
+        let border = match alert_color(&state) {
+            Some(color) => {
+                title.push(Span::styled(
+                    format!(" ▲ {} ", alert_label(&state)),
+                    Style::new().fg(color).bold(),
+                ));
+                pulsing_accent(color, self.global_tick, 10)
+            }
+            None => pulsing_accent(BORDER_DIM, self.global_tick, 15),
+        };
+
+        let block = Block::default()
+            .title(Line::from(title))
+            .title_bottom(Line::from(vec![
+                Span::styled(
+                    format!(" now {:>3}{} ", stats.last, spec.unit),
+                    Style::new().fg(BG_CANVAS).bg(accent).bold(),
+                ),
+                Span::styled(" │ ", Style::new().fg(FG_DIM)),
+                Span::styled("avg", Style::new().fg(FG_MUTED)),
+                Span::styled(format!(" {:.1}", stats.avg), Style::new().fg(FG_PRIMARY)),
+                Span::styled(" │ ", Style::new().fg(FG_DIM)),
+                Span::styled("trend", Style::new().fg(FG_MUTED)),
+                Span::styled(
+                    format!(
+                        " {}{}",
+                        stats.trend_symbol(self.global_tick),
+                        stats.trend_magnitude()
+                    ),
+                    Style::new().fg(accent).bold(),
+                ),
+            ]))
+            .borders(Borders::ALL)
+            .border_type(if selected {
+                BorderType::Thick
+            } else {
+                BorderType::Rounded
+            })
+            .border_style(Style::new().fg(border))
+            .style(Style::new().bg(BG_PANEL));
+
+        // Only the latest readings that fit are handed over, so the newest is always on screen.
+        let values = series.as_slice();
+        let visible = &values[values.len().saturating_sub(inner_area.width as usize)..];
+        let sparkline = Sparkline::default()
+            .block(block)
+            .data(visible)
+            .max(stats.max.max(1))
+            .style(Style::new().fg(accent));
+
+        sparkline.render(area, buf);
     }
 }
 
 /// Build the spark widget from existing data (keeps animation state in App)
-pub fn third_screen_from(
-    data: &[Vec<u64>; 3],
+pub fn third_screen_from<'a>(
+    series: &'a [SeriesSpec],
+    feed: &'a Feed,
+    inspector: &'a Inspector,
     page: usize,
     total: usize,
     screen_tick: u64,
     global_tick: u64,
-) -> SparkWidget {
+) -> SparkWidget<'a> {
     SparkWidget {
-        data: [data[0].clone(), data[1].clone(), data[2].clone()],
+        series,
+        history: inspector.history(&feed.history),
+        alerts: &feed.alerts,
+        inspector,
         page,
         total,
         screen_tick,
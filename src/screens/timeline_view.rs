@@ -0,0 +1,200 @@
+use ratatui::{
+    prelude::*,
+    style::Stylize,
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Padding},
+};
+
+use super::text_layout;
+use super::theme::*;
+use crate::calendar::{self, Month, Period};
+use crate::content::{EXPERIENCE, Experience, recent_first};
+use crate::timeline::{MAX_ZOOM, Timeline};
+
+/// Rows under the bars: the overlap strip, the axis and its labels.
+const AXIS_ROWS: u16 = 3;
+
+/// Every role as a bar on a month axis, ongoing roles running up to the current month.
+pub struct TimelineViewWidget<'a> {
+    timeline: &'a Timeline,
+    highlighted: Option<usize>,
+}
+
+pub fn timeline_view(timeline: &Timeline, highlighted: Option<usize>) -> TimelineViewWidget<'_> {
+    TimelineViewWidget {
+        timeline,
+        highlighted,
+    }
+}
+
+/// Shade for the number of roles active in a column.
+fn overlap_symbol(count: usize) -> &'static str {
+    match count {
+        0 => " ",
+        1 => "░",
+        2 => "▒",
+        3 => "▓",
+        _ => "█",
+    }
+}
+
+impl Widget for TimelineViewWidget<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let now = Month::now();
+        // Oldest first, so the bars step down towards the present.
+        let roles: Vec<(usize, &Experience)> = recent_first(EXPERIENCE, |entry| entry.period)
+            .into_iter()
+            .rev()
+            .map(|index| (index, &EXPERIENCE[index]))
+            .collect();
+
+        // Zooming keeps the latest months, so "now" is always the right edge.
+        let first = roles
+            .iter()
+            .map(|(_, entry)| entry.period.start.index())
+            .min()
+            .unwrap_or(now.index());
+        let career = now.index() - first + 1;
+        let months = (career >> self.timeline.zoom).max(3);
+        let start = now.index() - months + 1;
+        let ongoing = roles
+            .iter()
+            .filter(|(_, entry)| entry.period.end.is_none())
+            .count();
+
+        let block = Block::default()
+            .title(Span::styled(
+                format!("── Career Timeline · {ongoing} roles ongoing ──"),
+                Style::new().fg(ACCENT_TEAL).bold(),
+            ))
+            .title_bottom(
+                Line::from(Span::styled(
+                    format!(
+                        " {} {} – now · zoom {}/{} ",
+                        Month::from_index(start).name(),
+                        Month::from_index(start).year,
+                        self.timeline.zoom,
+                        MAX_ZOOM
+                    ),
+                    Style::new().fg(FG_MUTED),
+                ))
+                .right_aligned(),
+            )
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::new().fg(BORDER_DIM))
+            .padding(Padding::horizontal(1))
+            .style(Style::new().bg(BG_PANEL));
+        let inner = block.inner(area);
+        block.render(area, buf);
+
+        if inner.width < 24 || inner.height < AXIS_ROWS + 1 {
+            return;
+        }
+
+        let label_width = (inner.width / 4).clamp(8, 20);
+        let chart_x = inner.x + label_width + 1;
+        let chart_width = inner.right() - chart_x;
+        // First month shown in a column; a column spans up to the next column's month.
+        let month_at = |column: u16| start + i32::from(column) * months / i32::from(chart_width);
+        let covers = |period: &Period, column: u16| {
+            let from = month_at(column);
+            let until = month_at(column + 1).max(from + 1);
+            period.start.index() < until && period.last(now).index() >= from
+        };
+
+        // Latest roles win when there is no room for all of them, with a blank row between
+        // bars when there is.
+        let room = inner.height - AXIS_ROWS;
+        let shown = roles.len().min(room as usize);
+        let step = if room as usize >= shown * 2 { 2 } else { 1 };
+        let mut y = inner.y;
+        for (index, entry) in &roles[roles.len() - shown..] {
+            let period = &entry.period;
+            let mut label_style = Style::new().fg(entry.accent).bold();
+            if self.highlighted == Some(*index) {
+                label_style = label_style.reversed();
+            }
+            text_layout::draw(
+                buf,
+                inner,
+                inner.x,
+                y,
+                &text_layout::pad(entry.company, label_width as usize),
+                label_style,
+            );
+            let mut end_x = None;
+            for column in 0..chart_width {
+                let x = chart_x + column;
+                if !covers(period, column) {
+                    continue;
+                }
+                // An arrow where the role started before the visible window.
+                let symbol = if column == 0 && period.start.index() < start {
+                    "◀"
+                } else {
+                    "█"
+                };
+                text_layout::draw(buf, inner, x, y, symbol, Style::new().fg(entry.accent));
+                end_x = Some(x + 1);
+            }
+            // The role's length just past its bar, when it fits before the right edge.
+            let length = calendar::duration(period.months(now));
+            if let Some(x) = end_x
+                && usize::from(inner.right().saturating_sub(x)) > text_layout::width(&length)
+            {
+                text_layout::draw(buf, inner, x + 1, y, &length, Style::new().fg(FG_MUTED));
+            }
+            y += step;
+        }
+
+        // How many roles run at once in each column.
+        let overlap_y = inner.bottom() - AXIS_ROWS;
+        let axis_y = overlap_y + 1;
+        let labels_y = axis_y + 1;
+        let muted = Style::new().fg(FG_MUTED);
+        text_layout::draw(buf, inner, inner.x, overlap_y, "overlap", muted);
+        for column in 0..chart_width {
+            let count = roles
+                .iter()
+                .filter(|(_, entry)| covers(&entry.period, column))
+                .count();
+            let color = if count > 1 { ACCENT_GOLD } else { FG_DIM };
+            let x = chart_x + column;
+            text_layout::draw(
+                buf,
+                inner,
+                x,
+                overlap_y,
+                overlap_symbol(count),
+                Style::new().fg(color),
+            );
+        }
+
+        // Axis with a tick at every month (or year) boundary, labelled where there is room.
+        let wide_months = chart_width as i32 >= months * 4;
+        let mut free_x = chart_x;
+        for column in 0..chart_width {
+            let x = chart_x + column;
+            let month = Month::from_index(month_at(column));
+            let boundary = column == 0 || month_at(column - 1) != month_at(column);
+            let labelled = boundary && (wide_months || month.month == 1 || column == 0);
+            let tick = if labelled { "┴" } else { "─" };
+            text_layout::draw(buf, inner, x, axis_y, tick, Style::new().fg(BORDER_DIM));
+            if !labelled || x < free_x {
+                continue;
+            }
+            let label = if wide_months && month.month != 1 {
+                month.name().to_string()
+            } else {
+                month.year.to_string()
+            };
+            free_x = text_layout::draw(buf, inner, x, labels_y, &label, muted) + 1;
+        }
+        let now_x = inner.right().saturating_sub(3);
+        if now_x >= free_x {
+            let style = Style::new().fg(ACCENT_TEAL).bold();
+            text_layout::draw(buf, inner, now_x, labels_y, "now", style);
+        }
+    }
+}
@@ -0,0 +1,165 @@
+//! Screen-change transitions composited from the outgoing and incoming frame buffers.
+
+use std::time::{Duration, Instant};
+
+use ratatui::prelude::*;
+
+use super::animation::Easing;
+use super::theme::*;
+
+/// Visual style of a screen change.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum TransitionKind {
+    /// The old screen slides out while the new one slides in behind it.
+    #[default]
+    Slide,
+    /// Cells cross-fade their colors and swap glyphs in a scattered pattern.
+    Dissolve,
+    /// A bright edge sweeps across, revealing the new screen behind it.
+    Wipe,
+}
+
+impl TransitionKind {
+    /// Parse a config value such as `slide`, `dissolve` or `wipe`.
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "slide" => Some(Self::Slide),
+            "dissolve" | "crossfade" => Some(Self::Dissolve),
+            "wipe" => Some(Self::Wipe),
+            _ => None,
+        }
+    }
+}
+
+/// Which way the user is paging; slides and wipes move with it.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Travel {
+    /// `n`: content moves right to left.
+    Forward,
+    /// `p`: content moves left to right.
+    Backward,
+}
+
+/// An in-flight transition away from the last frame drawn before the screen changed.
+#[derive(Debug)]
+pub struct Transition {
+    kind: TransitionKind,
+    travel: Travel,
+    from: Buffer,
+    started: Instant,
+    duration: Duration,
+}
+
+impl Transition {
+    pub fn new(kind: TransitionKind, travel: Travel, from: Buffer, duration: Duration) -> Self {
+        Self {
+            kind,
+            travel,
+            from,
+            started: Instant::now(),
+            duration,
+        }
+    }
+
+    /// Eased progress in [0.0, 1.0].
+    pub fn progress(&self) -> f64 {
+        if self.duration.is_zero() {
+            return 1.0;
+        }
+        let linear = self.started.elapsed().as_secs_f64() / self.duration.as_secs_f64();
+        Easing::EaseInOut.apply(linear)
+    }
+
+    pub fn finished(&self) -> bool {
+        self.started.elapsed() >= self.duration
+    }
+
+    /// Blend the outgoing frame into `to`, which already holds the incoming screen.
+    pub fn compose(&self, to: &mut Buffer) {
+        // A resize mid-transition leaves nothing sensible to blend with.
+        if self.from.area != to.area || self.finished() {
+            return;
+        }
+        let t = self.progress();
+        match self.kind {
+            TransitionKind::Slide => self.slide(to, t),
+            TransitionKind::Dissolve => self.dissolve(to, t),
+            TransitionKind::Wipe => self.wipe(to, t),
+        }
+    }
+
+    fn slide(&self, to: &mut Buffer, t: f64) {
+        let area = to.area;
+        let width = area.width as usize;
+        let offset = ((width as f64) * t).round() as usize;
+        let incoming = to.clone();
+        for y in area.top()..area.bottom() {
+            for col in 0..width {
+                // Position of this column on a strip of [old | new] (forward) or [new | old].
+                let strip = match self.travel {
+                    Travel::Forward => col + offset,
+                    Travel::Backward => col + width - offset,
+                };
+                let (source, src_col) = match (self.travel, strip < width) {
+                    (Travel::Forward, true) | (Travel::Backward, false) => {
+                        (&self.from, strip % width)
+                    }
+                    _ => (&incoming, strip % width),
+                };
+                let x = area.x + col as u16;
+                to[(x, y)] = source[(area.x + src_col as u16, y)].clone();
+            }
+        }
+    }
+
+    fn dissolve(&self, to: &mut Buffer, t: f64) {
+        let area = to.area;
+        for y in area.top()..area.bottom() {
+            for x in area.left()..area.right() {
+                let old = &self.from[(x, y)];
+                let cell = &mut to[(x, y)];
+                let fg = color_lerp(old.fg, cell.fg, t);
+                let bg = color_lerp(old.bg, cell.bg, t);
+                if t < scatter(x, y) {
+                    let symbol = old.symbol().to_string();
+                    cell.set_symbol(&symbol);
+                }
+                cell.set_fg(fg).set_bg(bg);
+            }
+        }
+    }
+
+    fn wipe(&self, to: &mut Buffer, t: f64) {
+        let area = to.area;
+        let edge = (area.width as f64 * t).round() as u16;
+        for y in area.top()..area.bottom() {
+            for col in 0..area.width {
+                // Columns the edge has already passed show the new screen.
+                let revealed = match self.travel {
+                    Travel::Forward => col < edge,
+                    Travel::Backward => col >= area.width - edge,
+                };
+                let x = area.x + col;
+                if !revealed {
+                    to[(x, y)] = self.from[(x, y)].clone();
+                }
+                let at_edge = match self.travel {
+                    Travel::Forward => col + 1 == edge,
+                    Travel::Backward => col == area.width - edge,
+                };
+                if at_edge {
+                    to[(x, y)].set_symbol("▐").set_fg(ACCENT_TEAL);
+                }
+            }
+        }
+    }
+}
+
+/// Deterministic per-cell threshold in [0.0, 1.0) so the dissolve looks scattered, not banded.
+fn scatter(x: u16, y: u16) -> f64 {
+    let mut h = (x as u32).wrapping_mul(0x9E37_79B1) ^ (y as u32).wrapping_mul(0x85EB_CA77);
+    h ^= h >> 15;
+    h = h.wrapping_mul(0x2C1B_3C6D);
+    h ^= h >> 12;
+    (h % 1000) as f64 / 1000.0
+}
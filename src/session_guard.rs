@@ -0,0 +1,90 @@
+//! Closing out a session's records exactly once, however the session ends.
+
+use std::ops::{Deref, DerefMut};
+
+/// A per-session record that notes how the session ended.
+pub trait Finish {
+    /// Note that the session ended for `reason`. [`SessionGuard`] calls this at most once.
+    fn finish(&mut self, reason: &str);
+}
+
+/// Owner of a session record that finishes it exactly once: on the first
+/// [`SessionGuard::finish`], or when dropped without one, e.g. on a panic.
+#[derive(Debug, Default)]
+pub struct SessionGuard<T: Finish> {
+    record: T,
+    finished: bool,
+}
+
+impl<T: Finish> SessionGuard<T> {
+    pub fn new(record: T) -> Self {
+        Self {
+            record,
+            finished: false,
+        }
+    }
+
+    /// Record the end of the session. Later calls, and the drop guard, are no-ops.
+    pub fn finish(&mut self, reason: &str) {
+        if !self.finished {
+            self.finished = true;
+            self.record.finish(reason);
+        }
+    }
+}
+
+impl<T: Finish> Deref for SessionGuard<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.record
+    }
+}
+
+impl<T: Finish> DerefMut for SessionGuard<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.record
+    }
+}
+
+impl<T: Finish> Drop for SessionGuard<T> {
+    fn drop(&mut self) {
+        let reason = if std::thread::panicking() {
+            "panic"
+        } else {
+            "dropped"
+        };
+        self.finish(reason);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Default)]
+    struct Reasons(Vec<String>);
+
+    impl Finish for &mut Reasons {
+        fn finish(&mut self, reason: &str) {
+            self.0.push(reason.to_string());
+        }
+    }
+
+    #[test]
+    fn finishes_once() {
+        let mut reasons = Reasons::default();
+        let mut guard = SessionGuard::new(&mut reasons);
+        guard.finish("quit");
+        guard.finish("hangup");
+        drop(guard);
+        assert_eq!(reasons.0, ["quit"]);
+    }
+
+    #[test]
+    fn drop_finishes_an_unfinished_session() {
+        let mut reasons = Reasons::default();
+        drop(SessionGuard::new(&mut reasons));
+        assert_eq!(reasons.0, ["dropped"]);
+    }
+}
@@ -0,0 +1,113 @@
+//! Idle timeout and maximum session length.
+//!
+//! Both limits warn the visitor for the last [`WARNING_WINDOW`] before they end the session.
+//! Any key resets the idle clock; the max-duration warning can be dismissed but its deadline
+//! still stands.
+
+use std::time::{Duration, Instant};
+
+use crate::config::Config;
+
+/// How long before a limit is reached the countdown is shown, capped at half the limit so short
+/// limits are not all warning.
+pub const WARNING_WINDOW: Duration = Duration::from_secs(60);
+
+/// Which limit is ending the session.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Limit {
+    Idle,
+    MaxDuration,
+}
+
+impl Limit {
+    /// Disconnect reason recorded in analytics.
+    pub fn reason(&self) -> &'static str {
+        match self {
+            Limit::Idle => "idle_timeout",
+            Limit::MaxDuration => "max_duration",
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct SessionTimer {
+    idle_timeout: Option<Duration>,
+    max_duration: Option<Duration>,
+    started: Instant,
+    last_input: Instant,
+    /// The visitor has acknowledged the max-duration warning.
+    dismissed: bool,
+}
+
+impl Default for SessionTimer {
+    fn default() -> Self {
+        Self::new(&Config::default())
+    }
+}
+
+impl SessionTimer {
+    pub fn new(config: &Config) -> Self {
+        let now = Instant::now();
+        Self {
+            idle_timeout: config.idle_timeout,
+            max_duration: config.max_session,
+            started: now,
+            last_input: now,
+            dismissed: false,
+        }
+    }
+
+    /// Note a key press. Returns whether it dismissed a visible warning.
+    pub fn on_input(&mut self) -> bool {
+        let warning = self.warning();
+        self.last_input = Instant::now();
+        if let Some((Limit::MaxDuration, _)) = warning {
+            self.dismissed = true;
+        }
+        warning.is_some()
+    }
+
+    /// The limit about to be reached and the time left, while its warning should be shown.
+    pub fn warning(&self) -> Option<(Limit, Duration)> {
+        let limits = if self.dismissed {
+            &[Limit::Idle][..]
+        } else {
+            &[Limit::Idle, Limit::MaxDuration][..]
+        };
+        limits
+            .iter()
+            .filter_map(|&limit| {
+                let budget = self.budget(limit)?;
+                let left = self.remaining(limit)?;
+                (left <= WARNING_WINDOW.min(budget / 2)).then_some((limit, left))
+            })
+            .min_by_key(|(_, left)| *left)
+    }
+
+    /// Time since the last key press, or since connecting.
+    pub fn since_input(&self) -> Duration {
+        self.last_input.elapsed()
+    }
+
+    /// The limit that has run out, if any.
+    pub fn expired(&self) -> Option<Limit> {
+        [Limit::MaxDuration, Limit::Idle]
+            .into_iter()
+            .find(|&limit| self.remaining(limit).is_some_and(|left| left.is_zero()))
+    }
+
+    fn budget(&self, limit: Limit) -> Option<Duration> {
+        match limit {
+            Limit::Idle => self.idle_timeout,
+            Limit::MaxDuration => self.max_duration,
+        }
+    }
+
+    fn remaining(&self, limit: Limit) -> Option<Duration> {
+        let since = match limit {
+            Limit::Idle => self.last_input,
+            Limit::MaxDuration => self.started,
+        };
+        Some(self.budget(limit)?.saturating_sub(since.elapsed()))
+    }
+}
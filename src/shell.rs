@@ -0,0 +1,437 @@
+//! A pretend shell over the portfolio content, for visitors who instinctively type commands.
+//!
+//! Nothing here touches the real filesystem: `ls` and `cat` browse a fixed tree built from
+//! [`crate::content`]. Line editing covers the readline basics (cursor movement, Home/End,
+//! Ctrl-A/E/U/W/L, history on Up/Down and Tab completion).
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use crate::calendar::{self, Month};
+use crate::content::{self, EXPERIENCE, PROJECTS, SKILLS, SkillCategory, recent_first};
+
+pub const PROMPT: &str = "visitor@karneeshkar.dev:~$ ";
+/// Scrollback lines kept; older output is dropped.
+const SCROLLBACK: usize = 500;
+const HISTORY: usize = 100;
+
+const COMMANDS: [&str; 12] = [
+    "help", "whoami", "ls", "cat", "skills", "contact", "history", "pwd", "echo", "clear", "exit",
+    "sudo",
+];
+
+/// How a line of output should be styled.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Tone {
+    /// An echoed prompt and command.
+    Command,
+    Text,
+    Heading,
+    Muted,
+    Error,
+}
+
+#[derive(Debug, Clone)]
+pub struct ShellLine {
+    pub tone: Tone,
+    pub text: String,
+}
+
+/// What a key press did, so the app can react and log it without the typed text.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Reaction {
+    /// The input line changed, or nothing happened.
+    Edit,
+    /// A command ran; carries the command name, or `unknown`.
+    Ran(&'static str),
+    /// The visitor asked to leave the shell.
+    Exit,
+}
+
+#[derive(Debug, Default)]
+pub struct Shell {
+    pub scrollback: Vec<ShellLine>,
+    pub input: String,
+    /// Cursor position in characters.
+    pub cursor: usize,
+    history: Vec<String>,
+    /// Index into `history` while browsing with Up/Down.
+    browsing: Option<usize>,
+    /// The line being typed before history browsing started.
+    draft: String,
+}
+
+impl Shell {
+    pub fn new() -> Self {
+        let mut shell = Self::default();
+        shell.print(
+            Tone::Heading,
+            format!("{} · portfolio shell", content::NAME),
+        );
+        shell.print(
+            Tone::Muted,
+            "Type `help` for commands, `exit` or Esc to go back.",
+        );
+        shell
+    }
+
+    pub fn on_key(&mut self, key: KeyEvent) -> Reaction {
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+        match key.code {
+            KeyCode::Esc => return Reaction::Exit,
+            KeyCode::Enter => return self.submit(),
+            KeyCode::Char('a') if ctrl => self.cursor = 0,
+            KeyCode::Char('e') if ctrl => self.cursor = self.input.chars().count(),
+            KeyCode::Char('u') if ctrl => {
+                self.input.drain(..self.byte_index(self.cursor));
+                self.cursor = 0;
+            }
+            KeyCode::Char('w') if ctrl => self.delete_word(),
+            KeyCode::Char('l') if ctrl => self.scrollback.clear(),
+            KeyCode::Char(c) if !ctrl => {
+                let at = self.byte_index(self.cursor);
+                self.input.insert(at, c);
+                self.cursor += 1;
+            }
+            KeyCode::Backspace if self.cursor > 0 => {
+                self.cursor -= 1;
+                let at = self.byte_index(self.cursor);
+                self.input.remove(at);
+            }
+            KeyCode::Delete if self.cursor < self.input.chars().count() => {
+                let at = self.byte_index(self.cursor);
+                self.input.remove(at);
+            }
+            KeyCode::Left => self.cursor = self.cursor.saturating_sub(1),
+            KeyCode::Right => self.cursor = (self.cursor + 1).min(self.input.chars().count()),
+            KeyCode::Home => self.cursor = 0,
+            KeyCode::End => self.cursor = self.input.chars().count(),
+            KeyCode::Up => self.browse_back(),
+            KeyCode::Down => self.browse_forward(),
+            KeyCode::Tab => self.complete(),
+            _ => {}
+        }
+        Reaction::Edit
+    }
+
+    fn byte_index(&self, chars: usize) -> usize {
+        self.input
+            .char_indices()
+            .nth(chars)
+            .map_or(self.input.len(), |(index, _)| index)
+    }
+
+    fn set_input(&mut self, input: String) {
+        self.cursor = input.chars().count();
+        self.input = input;
+    }
+
+    fn delete_word(&mut self) {
+        let end = self.byte_index(self.cursor);
+        let before = self.input[..end].trim_end();
+        let start = before.rfind(' ').map_or(0, |space| space + 1);
+        self.cursor = self.input[..start].chars().count();
+        self.input.drain(start..end);
+    }
+
+    fn browse_back(&mut self) {
+        let index = match self.browsing {
+            Some(0) => return,
+            Some(index) => index - 1,
+            None if self.history.is_empty() => return,
+            None => {
+                self.draft = self.input.clone();
+                self.history.len() - 1
+            }
+        };
+        self.browsing = Some(index);
+        self.set_input(self.history[index].clone());
+    }
+
+    fn browse_forward(&mut self) {
+        let Some(index) = self.browsing else {
+            return;
+        };
+        if index + 1 < self.history.len() {
+            self.browsing = Some(index + 1);
+            self.set_input(self.history[index + 1].clone());
+        } else {
+            self.browsing = None;
+            let draft = std::mem::take(&mut self.draft);
+            self.set_input(draft);
+        }
+    }
+
+    /// Complete the word before the cursor as a command (first word) or a path.
+    fn complete(&mut self) {
+        let end = self.byte_index(self.cursor);
+        let start = self.input[..end].rfind(' ').map_or(0, |space| space + 1);
+        let word = &self.input[start..end];
+        let candidates: Vec<String> = if start == 0 {
+            COMMANDS.iter().map(|command| command.to_string()).collect()
+        } else {
+            paths()
+        };
+        let matches: Vec<&String> = candidates
+            .iter()
+            .filter(|candidate| {
+                // Only offer entries inside the directory being typed, like a real shell.
+                candidate.strip_prefix(word).is_some_and(|rest| {
+                    let nested = rest.trim_end_matches('/').contains('/');
+                    let listed_dir = rest.is_empty() && word.ends_with('/');
+                    !nested && !listed_dir
+                })
+            })
+            .collect();
+        let Some(first) = matches.first() else {
+            return;
+        };
+        let common = matches.iter().fold(first.as_str(), |common, candidate| {
+            let shared = common
+                .char_indices()
+                .zip(candidate.chars())
+                .take_while(|((_, a), b)| a == b)
+                .last()
+                .map_or(0, |((index, a), _)| index + a.len_utf8());
+            &common[..shared]
+        });
+        let mut completion = common.to_string();
+        if matches.len() == 1 && !completion.ends_with('/') {
+            completion.push(' ');
+        } else if matches.len() > 1 && completion == word {
+            self.echo();
+            let listing: Vec<&str> = matches.iter().map(|candidate| candidate.as_str()).collect();
+            self.print(Tone::Text, listing.join("  "));
+        }
+        self.input.replace_range(start..end, &completion);
+        self.cursor = self.input[..start + completion.len()].chars().count();
+    }
+
+    fn print(&mut self, tone: Tone, text: impl Into<String>) {
+        self.scrollback.push(ShellLine {
+            tone,
+            text: text.into(),
+        });
+        if self.scrollback.len() > SCROLLBACK {
+            let excess = self.scrollback.len() - SCROLLBACK;
+            self.scrollback.drain(..excess);
+        }
+    }
+
+    /// Echo the prompt and current input, as a terminal would when Enter is pressed.
+    fn echo(&mut self) {
+        self.print(Tone::Command, format!("{PROMPT}{}", self.input));
+    }
+
+    fn submit(&mut self) -> Reaction {
+        self.echo();
+        let line = std::mem::take(&mut self.input);
+        self.cursor = 0;
+        self.browsing = None;
+        let line = line.trim();
+        if line.is_empty() {
+            return Reaction::Edit;
+        }
+        if self.history.last().is_none_or(|last| last != line) {
+            self.history.push(line.to_string());
+            if self.history.len() > HISTORY {
+                self.history.remove(0);
+            }
+        }
+        self.run(line)
+    }
+
+    fn run(&mut self, line: &str) -> Reaction {
+        let mut words = line.split_whitespace();
+        let command = words.next().unwrap_or_default();
+        let args: Vec<&str> = words.collect();
+        let name = COMMANDS
+            .iter()
+            .copied()
+            .find(|&known| known == command)
+            .unwrap_or("unknown");
+        match name {
+            "help" => self.help(),
+            "whoami" => {
+                self.print(Tone::Heading, content::NAME);
+                self.print(Tone::Text, content::TAGLINE);
+            }
+            "ls" => self.ls(&args),
+            "cat" if args.is_empty() => self.print(Tone::Error, "cat: missing file operand"),
+            "cat" => {
+                for arg in args {
+                    self.cat(arg);
+                }
+            }
+            "skills" => self.skills(args.contains(&"--sort")),
+            "contact" => self.contact(),
+            "history" => {
+                let lines: Vec<String> = self
+                    .history
+                    .iter()
+                    .enumerate()
+                    .map(|(index, entry)| format!("{:>4}  {entry}", index + 1))
+                    .collect();
+                for line in lines {
+                    self.print(Tone::Text, line);
+                }
+            }
+            "pwd" => self.print(Tone::Text, "/home/visitor"),
+            "echo" => self.print(Tone::Text, args.join(" ")),
+            "clear" => self.scrollback.clear(),
+            "exit" => return Reaction::Exit,
+            "sudo" => self.print(
+                Tone::Error,
+                "visitor is not in the sudoers file. This incident will be reported.",
+            ),
+            _ => self.print(
+                Tone::Error,
+                format!("{command}: command not found. Try `help`."),
+            ),
+        }
+        Reaction::Ran(name)
+    }
+
+    fn help(&mut self) {
+        self.print(Tone::Heading, "Available commands");
+        for (usage, description) in [
+            ("whoami", "who runs this place"),
+            ("ls [dir]", "list experience/, projects/ and more"),
+            ("cat <file>", "read an entry, e.g. cat experience/visteon"),
+            ("skills [--sort]", "skills by category, optionally by level"),
+            ("contact", "how to get in touch"),
+            ("history", "commands typed this session"),
+            ("clear", "clear the screen (or Ctrl-L)"),
+            ("exit", "back to the portfolio (or Esc)"),
+        ] {
+            self.print(Tone::Text, format!("  {usage:<16} {description}"));
+        }
+    }
+
+    fn ls(&mut self, args: &[&str]) {
+        let target = args.first().map_or("", |arg| normalize(arg));
+        let listing: Vec<&str> = match target {
+            "" => vec!["experience/", "projects/", "contact.txt", "skills.txt"],
+            "experience" => recent_first(EXPERIENCE, |entry| entry.period)
+                .into_iter()
+                .map(|index| EXPERIENCE[index].slug)
+                .collect(),
+            "projects" => recent_first(PROJECTS, |project| project.period)
+                .into_iter()
+                .map(|index| PROJECTS[index].slug)
+                .collect(),
+            "contact.txt" | "skills.txt" => vec![target],
+            _ => {
+                self.print(
+                    Tone::Error,
+                    format!("ls: cannot access '{target}': No such file or directory"),
+                );
+                return;
+            }
+        };
+        self.print(Tone::Text, listing.join("  "));
+    }
+
+    fn cat(&mut self, arg: &str) {
+        let path = normalize(arg);
+        if let Some(slug) = path.strip_prefix("experience/")
+            && let Some(entry) = EXPERIENCE.iter().find(|entry| entry.slug == slug)
+        {
+            self.print(Tone::Heading, format!("{} · {}", entry.company, entry.role));
+            let months = entry.period.months(Month::now());
+            self.print(
+                Tone::Muted,
+                format!("{} · {}", entry.period.label(), calendar::duration(months)),
+            );
+            for highlight in entry.highlights {
+                self.print(Tone::Text, format!("- {highlight}"));
+            }
+            return;
+        }
+        if let Some(slug) = path.strip_prefix("projects/")
+            && let Some(project) = PROJECTS.iter().find(|project| project.slug == slug)
+        {
+            self.print(
+                Tone::Heading,
+                format!("{} ({})", project.name, project.period.label()),
+            );
+            self.print(Tone::Text, project.summary);
+            self.print(Tone::Muted, format!("stack: {}", project.stack));
+            return;
+        }
+        match path {
+            "contact.txt" => self.contact(),
+            "skills.txt" => self.skills(false),
+            "experience" | "projects" => {
+                self.print(Tone::Error, format!("cat: {path}: Is a directory"));
+            }
+            _ => self.print(
+                Tone::Error,
+                format!("cat: {path}: No such file or directory"),
+            ),
+        }
+    }
+
+    fn skills(&mut self, sorted: bool) {
+        for category in SkillCategory::in_use() {
+            let mut skills: Vec<_> = SKILLS
+                .iter()
+                .filter(|skill| skill.category == category)
+                .collect();
+            if sorted {
+                skills.sort_by_key(|skill| std::cmp::Reverse(skill.level));
+            }
+            self.print(Tone::Heading, category.label());
+            for skill in skills {
+                let filled = usize::from(skill.level / 5);
+                self.print(
+                    Tone::Text,
+                    format!(
+                        "  {:<16}{}{} {:>3}%{}",
+                        skill.name,
+                        "█".repeat(filled),
+                        "░".repeat(20 - filled),
+                        skill.level,
+                        skill
+                            .years
+                            .map_or(String::new(), |years| format!(" · {years}y"))
+                    ),
+                );
+            }
+        }
+    }
+
+    fn contact(&mut self) {
+        self.print(Tone::Text, format!("email     {}", content::EMAIL));
+        self.print(Tone::Text, format!("github    {}", content::GITHUB));
+        self.print(Tone::Text, format!("linkedin  {}", content::LINKEDIN));
+    }
+}
+
+/// Strip `~/`, `./` and trailing slashes so `~/projects/` and `projects` name the same thing.
+fn normalize(path: &str) -> &str {
+    let path = path
+        .strip_prefix("~/")
+        .or_else(|| path.strip_prefix("./"))
+        .unwrap_or(path);
+    let path = path.trim_end_matches('/');
+    if path == "~" || path == "." { "" } else { path }
+}
+
+/// Every path `cat` and `ls` understand, for Tab completion.
+fn paths() -> Vec<String> {
+    let mut paths: Vec<String> = ["experience/", "projects/", "contact.txt", "skills.txt"]
+        .iter()
+        .map(|path| path.to_string())
+        .collect();
+    paths.extend(
+        EXPERIENCE
+            .iter()
+            .map(|entry| format!("experience/{}", entry.slug)),
+    );
+    paths.extend(
+        PROJECTS
+            .iter()
+            .map(|project| format!("projects/{}", project.slug)),
+    );
+    paths
+}
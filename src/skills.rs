@@ -0,0 +1,187 @@
+//! Category, ordering, chart style and skill selection for the Skills screen.
+
+use crossterm::event::{KeyCode, KeyEvent};
+
+use crate::{
+    content::{SKILLS, Skill, SkillCategory},
+    input::KeyHandler,
+};
+
+/// An entry a skill links to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Link {
+    /// Index into [`EXPERIENCE`](crate::content::EXPERIENCE).
+    Experience(usize),
+    /// Index into [`PROJECTS`](crate::content::PROJECTS).
+    Project(usize),
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum SkillSort {
+    /// Strongest first, the order the content lists them in.
+    #[default]
+    Proficiency,
+    Name,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum SkillView {
+    #[default]
+    Gauges,
+    Bars,
+    Radar,
+}
+
+#[derive(Debug, Default)]
+pub struct Skills {
+    /// Index into [`SkillCategory::in_use`].
+    pub category: usize,
+    pub sort: SkillSort,
+    pub view: SkillView,
+    /// Index into [`Skills::visible`].
+    pub selected: usize,
+    /// `true` while the selected skill's links are open.
+    pub browsing: bool,
+    /// Index into [`Skills::links`] while browsing.
+    pub link: usize,
+    /// Link chosen with Enter, for the app to navigate to and clear.
+    pub follow: Option<Link>,
+}
+
+impl SkillSort {
+    pub fn label(self) -> &'static str {
+        match self {
+            SkillSort::Proficiency => "by proficiency",
+            SkillSort::Name => "by name",
+        }
+    }
+}
+
+impl SkillView {
+    pub fn label(self) -> &'static str {
+        match self {
+            SkillView::Gauges => "gauges",
+            SkillView::Bars => "bar chart",
+            SkillView::Radar => "radar",
+        }
+    }
+
+    fn next(self) -> SkillView {
+        match self {
+            SkillView::Gauges => SkillView::Bars,
+            SkillView::Bars => SkillView::Radar,
+            SkillView::Radar => SkillView::Gauges,
+        }
+    }
+}
+
+impl Skills {
+    pub fn category(&self) -> SkillCategory {
+        SkillCategory::in_use()[self.category]
+    }
+
+    /// The selected category's skills in the selected order.
+    pub fn visible(&self) -> Vec<&'static Skill> {
+        let mut skills: Vec<&Skill> = SKILLS
+            .iter()
+            .filter(|skill| skill.category == self.category())
+            .collect();
+        match self.sort {
+            SkillSort::Proficiency => {
+                skills.sort_by(|a, b| b.level.cmp(&a.level).then(a.name.cmp(b.name)));
+            }
+            SkillSort::Name => {
+                skills.sort_by_key(|skill| skill.name.to_lowercase());
+            }
+        }
+        skills
+    }
+
+    pub fn skill(&self) -> &'static Skill {
+        let skills = self.visible();
+        skills[self.selected.min(skills.len() - 1)]
+    }
+
+    /// Experience entries, then projects, that used the selected skill.
+    pub fn links(&self) -> Vec<Link> {
+        let skill = self.skill();
+        let experience = skill.experience().map(|(index, _)| Link::Experience(index));
+        let projects = skill.projects().map(|(index, _)| Link::Project(index));
+        experience.chain(projects).collect()
+    }
+}
+
+impl KeyHandler for Skills {
+    type Context = ();
+
+    fn on_key(&mut self, key: KeyEvent, _: &()) -> Option<&'static str> {
+        if self.browsing {
+            let links = self.links();
+            match key.code {
+                KeyCode::Down | KeyCode::Char('j') => {
+                    self.link = (self.link + 1) % links.len();
+                }
+                KeyCode::Up | KeyCode::Char('k') => {
+                    self.link = (self.link + links.len() - 1) % links.len();
+                }
+                KeyCode::Enter | KeyCode::Char('l') | KeyCode::Right => {
+                    self.follow = links.get(self.link).copied();
+                    return Some("skill-follow");
+                }
+                KeyCode::Esc | KeyCode::Backspace | KeyCode::Char('h') | KeyCode::Left => {
+                    self.browsing = false;
+                    return Some("skill-close");
+                }
+                _ => return None,
+            }
+            return Some("skill-link");
+        }
+
+        let categories = SkillCategory::in_use().len();
+        let count = self.visible().len();
+        match key.code {
+            KeyCode::Right | KeyCode::Char('l') | KeyCode::Tab => {
+                self.category = (self.category + 1) % categories;
+                self.selected = 0;
+                Some("skills-category")
+            }
+            KeyCode::Left | KeyCode::Char('h') | KeyCode::BackTab => {
+                self.category = (self.category + categories - 1) % categories;
+                self.selected = 0;
+                Some("skills-category")
+            }
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.selected = (self.selected + 1) % count;
+                Some("skill-select")
+            }
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.selected = (self.selected + count - 1) % count;
+                Some("skill-select")
+            }
+            KeyCode::Enter if !self.links().is_empty() => {
+                self.browsing = true;
+                self.link = 0;
+                Some("skill-open")
+            }
+            KeyCode::Char('s') => {
+                // Keep the same skill selected in the new order.
+                let name = self.skill().name;
+                self.sort = match self.sort {
+                    SkillSort::Proficiency => SkillSort::Name,
+                    SkillSort::Name => SkillSort::Proficiency,
+                };
+                self.selected = self
+                    .visible()
+                    .iter()
+                    .position(|skill| skill.name == name)
+                    .unwrap_or(0);
+                Some("skills-sort")
+            }
+            KeyCode::Char('v') => {
+                self.view = self.view.next();
+                Some("skills-view")
+            }
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,40 @@
+//! Helpers for files that sessions share in the state directory.
+
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime},
+};
+
+/// Replace the file at `path` with `bytes` in one step, so other sessions never read half of it.
+/// The temporary file is named after this process, so concurrent writers never share one.
+pub fn atomic_write(path: &Path, bytes: &[u8]) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let mut temp = path.as_os_str().to_owned();
+    temp.push(format!(".{}.tmp", std::process::id()));
+    fs::write(&temp, bytes)?;
+    fs::rename(temp, path)
+}
+
+/// Every file in `dir`, paired with whether it has gone unmodified for longer than `max_age`.
+/// Sessions refresh their shared files as a heartbeat, so a stale one was left behind by a
+/// session that ended or died. Files whose age cannot be read count as fresh.
+pub fn stale_entries(dir: &Path, max_age: Duration) -> Vec<(PathBuf, bool)> {
+    let now = SystemTime::now();
+    fs::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .map(|entry| {
+            let stale = entry
+                .metadata()
+                .and_then(|meta| meta.modified())
+                .ok()
+                .and_then(|modified| now.duration_since(modified).ok())
+                .is_some_and(|age| age > max_age);
+            (entry.path(), stale)
+        })
+        .collect()
+}
@@ -0,0 +1,679 @@
+//! Background sampler that feeds the Telemetry screen.
+//!
+//! The series shown are configurable (see [`parse_series`]); each one draws from a [`Source`],
+//! either a synthetic signal or a reading of the host the server runs on. A [`Monitor`] watches
+//! the histories for readings past a series' thresholds or far outside its recent range.
+
+use std::{collections::VecDeque, f64::consts::TAU, time::Duration};
+
+use ratatui::style::Color;
+use tokio::{sync::mpsc, time};
+
+use crate::{host, ring::Ring, screens::theme::*};
+
+/// One reading per series, taken at the same instant, in series order.
+pub type Sample = Vec<u64>;
+
+/// Where a series' readings come from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Source {
+    /// Uniform noise in `0..100`.
+    Random,
+    /// A slow sine wave with a little noise, between about 10 and 90.
+    Wave,
+    /// A bounded random walk in `0..=100`.
+    Walk,
+    /// One-minute load average of the host, times 100.
+    Load,
+    /// Share of the host's memory in use, in percent.
+    Memory,
+}
+
+impl Source {
+    pub fn parse(name: &str) -> Option<Self> {
+        match name.trim().to_ascii_lowercase().as_str() {
+            "random" => Some(Source::Random),
+            "wave" => Some(Source::Wave),
+            "walk" => Some(Source::Walk),
+            "load" => Some(Source::Load),
+            "memory" | "mem" => Some(Source::Memory),
+            _ => None,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Source::Random => "random",
+            Source::Wave => "wave",
+            Source::Walk => "walk",
+            Source::Load => "load",
+            Source::Memory => "memory",
+        }
+    }
+}
+
+/// One telemetry series as configured.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SeriesSpec {
+    pub name: String,
+    /// Appended to readings, e.g. `%`; may be empty.
+    pub unit: String,
+    pub accent: Color,
+    pub source: Source,
+    /// Readings at or above this put the series in [`Level::Warning`].
+    pub warning: Option<u64>,
+    /// Readings at or above this put the series in [`Level::Critical`].
+    pub critical: Option<u64>,
+}
+
+impl SeriesSpec {
+    /// The threshold that starts `level`, if one is set.
+    pub fn limit(&self, level: Level) -> Option<u64> {
+        match level {
+            Level::Normal => None,
+            Level::Warning => self.warning,
+            Level::Critical => self.critical,
+        }
+    }
+
+    pub fn level(&self, value: u64) -> Level {
+        if self.critical.is_some_and(|limit| value >= limit) {
+            Level::Critical
+        } else if self.warning.is_some_and(|limit| value >= limit) {
+            Level::Warning
+        } else {
+            Level::Normal
+        }
+    }
+}
+
+/// Accents handed out in order to series that do not name one.
+const PALETTE: [Color; 6] = [
+    ACCENT_TEAL,
+    ACCENT_VIOLET,
+    ACCENT_GOLD,
+    ACCENT_BLUE,
+    ACCENT_GREEN,
+    ACCENT_RED,
+];
+
+/// The three synthetic signals shown when nothing is configured.
+pub fn default_series() -> Vec<SeriesSpec> {
+    ["Signal Alpha", "Signal Beta", "Signal Gamma"]
+        .into_iter()
+        .zip(PALETTE)
+        .map(|(name, accent)| SeriesSpec {
+            name: name.to_string(),
+            unit: String::new(),
+            accent,
+            source: Source::Random,
+            warning: None,
+            critical: None,
+        })
+        .collect()
+}
+
+/// Parse comma-separated `name:source[:unit[:accent[:warning[:critical]]]]` entries, e.g.
+/// `Load:load::blue:150:300,Memory:memory:%:gold,Noise:random`. Malformed entries are
+/// skipped; empty fields take their defaults.
+pub fn parse_series(spec: &str) -> Vec<SeriesSpec> {
+    spec.split(',')
+        .filter_map(|entry| {
+            let mut fields = entry.split(':');
+            let name = fields.next()?.trim();
+            let source = Source::parse(fields.next()?)?;
+            let unit = fields.next().unwrap_or("").trim();
+            let accent = fields.next().and_then(parse_accent);
+            let warning = fields.next().and_then(|limit| limit.trim().parse().ok());
+            let critical = fields.next().and_then(|limit| limit.trim().parse().ok());
+            (!name.is_empty()).then_some((name, source, unit, accent, warning, critical))
+        })
+        .enumerate()
+        .map(
+            |(index, (name, source, unit, accent, warning, critical))| SeriesSpec {
+                name: name.to_string(),
+                unit: unit.to_string(),
+                accent: accent.unwrap_or(PALETTE[index % PALETTE.len()]),
+                source,
+                warning,
+                critical,
+            },
+        )
+        .collect()
+}
+
+/// Produces readings for every configured series, keeping whatever state the synthetic
+/// signals need between ticks.
+#[derive(Debug, Default)]
+pub struct Sampler {
+    sources: Vec<Source>,
+    /// Last value of each walk.
+    walks: Vec<f64>,
+    tick: u64,
+}
+
+impl Sampler {
+    pub fn new(series: &[SeriesSpec]) -> Self {
+        Self {
+            sources: series.iter().map(|series| series.source).collect(),
+            walks: vec![50.0; series.len()],
+            tick: 0,
+        }
+    }
+
+    pub fn sample(&mut self) -> Sample {
+        self.tick += 1;
+        let tick = self.tick as f64;
+        self.sources
+            .iter()
+            .zip(&mut self.walks)
+            .enumerate()
+            .map(|(index, (source, walk))| match source {
+                Source::Random => rand::random_range(0..100),
+                Source::Wave => {
+                    // Offset each wave so several of them do not move in lockstep.
+                    let phase = TAU * (tick / 60.0 + index as f64 / 7.0);
+                    (50.0 + 35.0 * phase.sin() + rand::random_range(-5.0..5.0)).round() as u64
+                }
+                Source::Walk => {
+                    *walk = (*walk + rand::random_range(-8.0..8.0)).clamp(0.0, 100.0);
+                    walk.round() as u64
+                }
+                Source::Load => load_average().unwrap_or(0),
+                Source::Memory => memory_used().unwrap_or(0),
+            })
+            .collect()
+    }
+}
+
+/// One-minute load average times 100.
+fn load_average() -> Option<u64> {
+    let [one, _, _] = host::load_average()?;
+    Some((one * 100.0).round() as u64)
+}
+
+/// Percentage of memory in use.
+fn memory_used() -> Option<u64> {
+    Some(host::memory()?.used_percent()?.round() as u64)
+}
+
+/// How far past its thresholds a series' latest reading is.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    #[default]
+    Normal,
+    Warning,
+    Critical,
+}
+
+impl Level {
+    pub fn label(self) -> &'static str {
+        match self {
+            Level::Normal => "normal",
+            Level::Warning => "warning",
+            Level::Critical => "critical",
+        }
+    }
+}
+
+/// Readings at least this many standard deviations from the recent mean are anomalies.
+pub const ANOMALY_Z: f64 = 3.0;
+/// How many readings before the latest one the anomaly detector compares it against.
+const ANOMALY_WINDOW: usize = 30;
+/// A series only drops out of a level once its reading is this fraction below the threshold,
+/// so a signal hovering on the line does not flap.
+const HYSTERESIS: f64 = 0.05;
+/// Alerts kept for the history list.
+const ALERT_HISTORY: usize = 50;
+
+/// Standard score of the last reading in `history` against the [`ANOMALY_WINDOW`] readings
+/// before it, or `None` until there are that many.
+///
+/// The deviation is floored at one unit, so the first wobble of a flat signal is surprising
+/// rather than infinitely so.
+pub fn z_score(history: &[u64]) -> Option<f64> {
+    let (&last, before) = history.split_last()?;
+    let window = &before[before.len().saturating_sub(ANOMALY_WINDOW)..];
+    if window.len() < ANOMALY_WINDOW {
+        return None;
+    }
+    let count = window.len() as f64;
+    let mean = window.iter().sum::<u64>() as f64 / count;
+    let variance = window
+        .iter()
+        .map(|&value| (value as f64 - mean).powi(2))
+        .sum::<f64>()
+        / count;
+    Some((last as f64 - mean) / variance.sqrt().max(1.0))
+}
+
+/// Where a series stood after its latest reading.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct SeriesAlert {
+    pub level: Level,
+    pub z: Option<f64>,
+    pub anomalous: bool,
+}
+
+impl SeriesAlert {
+    pub fn is_active(&self) -> bool {
+        self.level > Level::Normal || self.anomalous
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AlertKind {
+    /// The series moved into this level; [`Level::Normal`] means it recovered.
+    Level(Level),
+    /// The series jumped this many standard deviations from its recent mean.
+    Anomaly(f64),
+}
+
+/// One change in a series' alert state.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Alert {
+    pub series: usize,
+    pub kind: AlertKind,
+    pub value: u64,
+    /// Unix milliseconds of the reading that raised it.
+    pub at: u64,
+}
+
+/// Threshold and anomaly state of every series, with a history of the changes.
+#[derive(Debug, Default)]
+pub struct Monitor {
+    /// One entry per series, in configuration order.
+    pub states: Vec<SeriesAlert>,
+    /// Newest first.
+    pub history: VecDeque<Alert>,
+}
+
+impl Monitor {
+    /// A monitor already in the state `data` describes, without alerts for getting there.
+    pub fn new(series: &[SeriesSpec], data: &[Series]) -> Self {
+        let mut monitor = Self::default();
+        monitor.observe(series, data, 0);
+        monitor.history.clear();
+        monitor
+    }
+
+    /// Re-evaluate every series after a new reading, recording an alert for each series that
+    /// changed level or has just become anomalous.
+    pub fn observe(&mut self, series: &[SeriesSpec], data: &[Series], at: u64) {
+        self.states.resize(series.len(), SeriesAlert::default());
+        for (index, (spec, history)) in series.iter().zip(data).enumerate() {
+            let Some(value) = history.last() else {
+                continue;
+            };
+            let previous = self.states[index];
+            let mut level = spec.level(value);
+            let holding = spec
+                .limit(previous.level)
+                .is_some_and(|limit| value as f64 >= limit as f64 * (1.0 - HYSTERESIS));
+            if level < previous.level && holding {
+                level = previous.level;
+            }
+            let z = z_score(history.as_slice());
+            let next = SeriesAlert {
+                level,
+                z,
+                anomalous: z.is_some_and(|z| z.abs() >= ANOMALY_Z),
+            };
+            self.states[index] = next;
+            let mut record = |kind| {
+                self.history.push_front(Alert {
+                    series: index,
+                    kind,
+                    value,
+                    at,
+                });
+            };
+            if next.level != previous.level {
+                record(AlertKind::Level(next.level));
+            }
+            if let Some(z) = z.filter(|_| next.anomalous && !previous.anomalous) {
+                record(AlertKind::Anomaly(z));
+            }
+        }
+        self.history.truncate(ALERT_HISTORY);
+    }
+
+    /// Series currently past a threshold or anomalous, with their state.
+    pub fn active(&self) -> impl Iterator<Item = (usize, &SeriesAlert)> {
+        self.states
+            .iter()
+            .enumerate()
+            .filter(|(_, state)| state.is_active())
+    }
+}
+
+/// One series' recent readings, with the statistics the screen needs kept up to date as they
+/// arrive, so reading them costs the same however long the history is.
+#[derive(Debug, Clone, Default)]
+pub struct Series {
+    readings: Ring<u64>,
+    /// Sequence number of the next reading.
+    next: u64,
+    sum: u128,
+    /// `(sequence, reading)` candidates for the maximum, readings decreasing from the front.
+    maxima: VecDeque<(u64, u64)>,
+    /// `(sequence, reading)` candidates for the minimum, readings increasing from the front.
+    minima: VecDeque<(u64, u64)>,
+}
+
+impl Series {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            readings: Ring::new(capacity),
+            ..Self::default()
+        }
+    }
+
+    pub fn push(&mut self, value: u64) {
+        let oldest = self.next - self.readings.len() as u64;
+        if let Some(evicted) = self.readings.push(value) {
+            self.sum -= u128::from(evicted);
+            for candidates in [&mut self.maxima, &mut self.minima] {
+                if candidates
+                    .front()
+                    .is_some_and(|&(sequence, _)| sequence == oldest)
+                {
+                    candidates.pop_front();
+                }
+            }
+        }
+        self.sum += u128::from(value);
+        while self.maxima.back().is_some_and(|&(_, max)| max <= value) {
+            self.maxima.pop_back();
+        }
+        self.maxima.push_back((self.next, value));
+        while self.minima.back().is_some_and(|&(_, min)| min >= value) {
+            self.minima.pop_back();
+        }
+        self.minima.push_back((self.next, value));
+        self.next += 1;
+    }
+
+    /// Oldest first.
+    pub fn as_slice(&self) -> &[u64] {
+        self.readings.as_slice()
+    }
+
+    pub fn len(&self) -> usize {
+        self.readings.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn first(&self) -> Option<u64> {
+        self.readings.first()
+    }
+
+    pub fn last(&self) -> Option<u64> {
+        self.readings.last()
+    }
+
+    pub fn max(&self) -> Option<u64> {
+        self.maxima.front().map(|&(_, max)| max)
+    }
+
+    pub fn min(&self) -> Option<u64> {
+        self.minima.front().map(|&(_, min)| min)
+    }
+
+    pub fn mean(&self) -> Option<f64> {
+        (!self.is_empty()).then(|| self.sum as f64 / self.len() as f64)
+    }
+}
+
+/// Recent readings of every series, oldest first, with the time of each sample.
+#[derive(Debug, Clone, Default)]
+pub struct History {
+    /// One history per series, in configuration order.
+    pub series: Vec<Series>,
+    /// Unix milliseconds of each sample.
+    pub times: Ring<u64>,
+}
+
+impl History {
+    /// Room for the last `capacity` samples of `count` series.
+    pub fn new(count: usize, capacity: usize) -> Self {
+        Self {
+            series: (0..count).map(|_| Series::new(capacity)).collect(),
+            times: Ring::new(capacity),
+        }
+    }
+
+    /// Append a sample taken at `at`, dropping the oldest once the history is full.
+    pub fn push(&mut self, sample: Sample, at: u64) {
+        for (series, value) in self.series.iter_mut().zip(sample) {
+            series.push(value);
+        }
+        self.times.push(at);
+    }
+}
+
+/// The live telemetry of a session: the histories and the alerts raised on them.
+#[derive(Debug, Default)]
+pub struct Feed {
+    pub history: History,
+    pub alerts: Monitor,
+}
+
+impl Feed {
+    /// Start from an already collected history without alerting on it.
+    pub fn new(series: &[SeriesSpec], history: History) -> Self {
+        Self {
+            alerts: Monitor::new(series, &history.series),
+            history,
+        }
+    }
+
+    /// Record a live sample and check it for alerts.
+    pub fn push(&mut self, series: &[SeriesSpec], sample: Sample, at: u64) {
+        self.history.push(sample, at);
+        self.alerts.observe(series, &self.history.series, at);
+    }
+}
+
+/// Distribution of a run of readings.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Summary {
+    pub min: u64,
+    pub max: u64,
+    pub mean: f64,
+    pub stddev: f64,
+    pub p50: u64,
+    pub p95: u64,
+    pub p99: u64,
+}
+
+impl Summary {
+    /// Summarize `values`, or `None` when there are none. Percentiles use the nearest rank.
+    pub fn of(values: &[u64]) -> Option<Self> {
+        let mut sorted = values.to_vec();
+        sorted.sort_unstable();
+        let (&min, &max) = (sorted.first()?, sorted.last()?);
+        let count = sorted.len() as f64;
+        let mean = sorted.iter().sum::<u64>() as f64 / count;
+        let variance = sorted
+            .iter()
+            .map(|&value| (value as f64 - mean).powi(2))
+            .sum::<f64>()
+            / count;
+        let percentile = |p: f64| {
+            let rank = (p / 100.0 * count).ceil() as usize;
+            sorted[rank.clamp(1, sorted.len()) - 1]
+        };
+        Some(Self {
+            min,
+            max,
+            mean,
+            stddev: variance.sqrt(),
+            p50: percentile(50.0),
+            p95: percentile(95.0),
+            p99: percentile(99.0),
+        })
+    }
+}
+
+/// Spawn a task that emits a fresh [`Sample`] every `period` until the receiver is dropped.
+pub fn spawn_sampler(period: Duration, mut sampler: Sampler) -> mpsc::Receiver<Sample> {
+    let (tx, rx) = mpsc::channel(16);
+    tokio::spawn(async move {
+        let mut ticker = time::interval(period);
+        loop {
+            ticker.tick().await;
+            if tx.send(sampler.sample()).await.is_err() {
+                break;
+            }
+        }
+    });
+    rx
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_series_reads_every_field() {
+        let series = parse_series("Load:load::blue:150:300, Memory : mem : % : #102030");
+        assert_eq!(series.len(), 2);
+        assert_eq!(series[0].name, "Load");
+        assert_eq!(series[0].source, Source::Load);
+        assert_eq!(series[0].unit, "");
+        assert_eq!(series[0].accent, ACCENT_BLUE);
+        assert_eq!(
+            (series[0].warning, series[0].critical),
+            (Some(150), Some(300))
+        );
+        assert_eq!(series[1].name, "Memory");
+        assert_eq!(series[1].source, Source::Memory);
+        assert_eq!(series[1].unit, "%");
+        assert_eq!(series[1].accent, Color::Rgb(0x10, 0x20, 0x30));
+        assert_eq!((series[1].warning, series[1].critical), (None, None));
+    }
+
+    #[test]
+    fn parse_series_skips_malformed_entries() {
+        assert!(parse_series("").is_empty());
+        assert!(parse_series(" , ,").is_empty());
+        assert!(parse_series("NoSource").is_empty());
+        assert!(parse_series("Name:bogus").is_empty());
+        assert!(parse_series(":random").is_empty());
+
+        // Skipped entries do not use up a palette color.
+        let series = parse_series("Bad:nope,First:wave,:walk,Second:walk");
+        let names: Vec<&str> = series.iter().map(|series| series.name.as_str()).collect();
+        assert_eq!(names, ["First", "Second"]);
+        assert_eq!(series[0].accent, PALETTE[0]);
+        assert_eq!(series[1].accent, PALETTE[1]);
+    }
+
+    #[test]
+    fn parse_series_defaults_bad_optional_fields() {
+        let series = parse_series("A:random:u:#12345:high:-1");
+        assert_eq!(series.len(), 1);
+        assert_eq!(series[0].accent, PALETTE[0]);
+        assert_eq!((series[0].warning, series[0].critical), (None, None));
+    }
+
+    #[test]
+    fn levels_start_at_their_thresholds() {
+        let spec = &parse_series("A:random::teal:50:80")[0];
+        assert_eq!(spec.level(49), Level::Normal);
+        assert_eq!(spec.level(50), Level::Warning);
+        assert_eq!(spec.level(79), Level::Warning);
+        assert_eq!(spec.level(80), Level::Critical);
+    }
+
+    #[test]
+    fn summary_of_nothing_is_none() {
+        assert_eq!(Summary::of(&[]), None);
+    }
+
+    #[test]
+    fn summary_of_one_sample() {
+        let summary = Summary::of(&[7]).unwrap();
+        assert_eq!((summary.min, summary.max), (7, 7));
+        assert_eq!(summary.mean, 7.0);
+        assert_eq!(summary.stddev, 0.0);
+        assert_eq!((summary.p50, summary.p95, summary.p99), (7, 7, 7));
+    }
+
+    #[test]
+    fn summary_of_two_samples_uses_nearest_rank() {
+        let summary = Summary::of(&[20, 10]).unwrap();
+        assert_eq!((summary.min, summary.max), (10, 20));
+        assert_eq!(summary.mean, 15.0);
+        assert_eq!(summary.stddev, 5.0);
+        // Rank ⌈0.5 × 2⌉ = 1 is the lower sample; ⌈0.95 × 2⌉ = 2 the upper one.
+        assert_eq!((summary.p50, summary.p95, summary.p99), (10, 20, 20));
+    }
+
+    #[test]
+    fn summary_percentiles_of_a_hundred() {
+        let values: Vec<u64> = (1..=100).rev().collect();
+        let summary = Summary::of(&values).unwrap();
+        assert_eq!((summary.p50, summary.p95, summary.p99), (50, 95, 99));
+        assert_eq!(summary.mean, 50.5);
+    }
+
+    /// Deterministic readings with runs, repeats and jumps.
+    fn readings(count: usize) -> Vec<u64> {
+        let mut state = 0x2545_f491_u64;
+        (0..count)
+            .map(|_| {
+                state = state
+                    .wrapping_mul(6364136223846793005)
+                    .wrapping_add(1442695040888963407);
+                (state >> 33) % 50
+            })
+            .collect()
+    }
+
+    #[test]
+    fn series_keeps_the_latest_readings_in_order() {
+        let mut series = Series::new(5);
+        for value in 0..12 {
+            series.push(value);
+        }
+        assert_eq!(series.as_slice(), [7, 8, 9, 10, 11]);
+        assert_eq!((series.first(), series.last()), (Some(7), Some(11)));
+    }
+
+    #[test]
+    fn rolling_stats_match_a_recompute() {
+        for capacity in [1, 2, 3, 8, 17] {
+            let mut series = Series::new(capacity);
+            assert_eq!(
+                (series.min(), series.max(), series.mean()),
+                (None, None, None)
+            );
+            let values = readings(200);
+            for (at, &value) in values.iter().enumerate() {
+                series.push(value);
+                let window = &values[(at + 1).saturating_sub(capacity)..=at];
+                assert_eq!(series.as_slice(), window);
+                assert_eq!(series.min(), window.iter().copied().min());
+                assert_eq!(series.max(), window.iter().copied().max());
+                let mean = window.iter().sum::<u64>() as f64 / window.len() as f64;
+                assert_eq!(series.mean(), Some(mean));
+            }
+        }
+    }
+
+    #[test]
+    fn rolling_stats_handle_equal_and_monotonic_runs() {
+        let mut series = Series::new(3);
+        for value in [5, 5, 5, 5, 1, 2, 3, 4, 9, 8, 7, 6] {
+            series.push(value);
+        }
+        assert_eq!(series.as_slice(), [8, 7, 6]);
+        assert_eq!((series.min(), series.max()), (Some(6), Some(8)));
+        assert_eq!(series.mean(), Some(7.0));
+    }
+}
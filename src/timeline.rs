@@ -0,0 +1,38 @@
+//! Gantt view of the experience entries on the Experience screen.
+
+use crossterm::event::{KeyCode, KeyEvent};
+
+use crate::input::KeyHandler;
+
+/// Each zoom step halves the months on screen, keeping the latest ones.
+pub const MAX_ZOOM: u8 = 3;
+
+#[derive(Debug, Default)]
+pub struct Timeline {
+    /// `true` while the Gantt view replaces the experience list.
+    pub open: bool,
+    /// `0` fits the whole career; each step doubles the columns per month.
+    pub zoom: u8,
+}
+
+impl KeyHandler for Timeline {
+    type Context = ();
+
+    fn on_key(&mut self, key: KeyEvent, _: &()) -> Option<&'static str> {
+        match key.code {
+            KeyCode::Char('t') => {
+                self.open = !self.open;
+                Some("timeline-toggle")
+            }
+            KeyCode::Char('+') | KeyCode::Char('=') if self.open => {
+                self.zoom = (self.zoom + 1).min(MAX_ZOOM);
+                Some("timeline-zoom")
+            }
+            KeyCode::Char('-') if self.open => {
+                self.zoom = self.zoom.saturating_sub(1);
+                Some("timeline-zoom")
+            }
+            _ => None,
+        }
+    }
+}